@@ -1,4 +1,4 @@
-#![allow(cast_possible_truncation)]
+#![allow(cast_possible_truncation, cast_lossless)]
 
 use rustc::lint::LateContext;
 use rustc::middle::const_eval::lookup_const_by_id;
@@ -4,9 +4,10 @@ use rustc_front::hir::*;
 use std::collections::HashMap;
 use std::collections::hash_map::Entry;
 use syntax::parse::token::InternedString;
+use syntax::ptr::P;
 use syntax::util::small_vector::SmallVector;
 use utils::{SpanlessEq, SpanlessHash};
-use utils::{get_parent_expr, in_macro, span_note_and_lint};
+use utils::{get_parent_expr, in_macro, snippet, span_lint_and_then, span_note_and_lint};
 
 /// **What it does:** This lint checks for consecutive `ifs` with the same condition. This lint is
 /// `Warn` by default.
@@ -56,12 +57,27 @@ declare_lint! {
     "`match` with identical arm bodies"
 }
 
+/// **What it does:** This lint checks for `if/else` whose *then* and *else* parts are the same
+/// call, except for a single argument.
+///
+/// **Why is this bad?** The reader has to compare both branches to find the difference, moving the
+/// `if` into the argument makes it obvious.
+///
+/// **Known problems:** Sometimes the duplication is wanted for symmetry with other branches.
+///
+/// **Example:** `if c { foo(a, 1) } else { foo(a, 2) }` could be `foo(a, if c { 1 } else { 2 })`
+declare_lint! {
+    pub IF_SAME_CALL_DIFFERENT_ARG,
+    Allow,
+    "if whose *then* and *else* blocks are the same call except for one argument"
+}
+
 #[derive(Copy, Clone, Debug)]
 pub struct CopyAndPaste;
 
 impl LintPass for CopyAndPaste {
     fn get_lints(&self) -> LintArray {
-        lint_array![IFS_SAME_COND, IF_SAME_THEN_ELSE, MATCH_SAME_ARMS]
+        lint_array![IFS_SAME_COND, IF_SAME_THEN_ELSE, MATCH_SAME_ARMS, IF_SAME_CALL_DIFFERENT_ARG]
     }
 }
 
@@ -79,6 +95,7 @@ impl LateLintPass for CopyAndPaste {
             lint_same_then_else(cx, blocks.as_slice());
             lint_same_cond(cx, conds.as_slice());
             lint_match_arms(cx, expr);
+            lint_same_call_different_arg(cx, expr);
         }
     }
 }
@@ -149,6 +166,72 @@ fn lint_match_arms(cx: &LateContext, expr: &Expr) {
     }
 }
 
+/// Implementation of `IF_SAME_CALL_DIFFERENT_ARG`.
+fn lint_same_call_different_arg(cx: &LateContext, expr: &Expr) {
+    if_let_chain! {[
+        let ExprIf(ref cond, ref then_block, Some(ref else_expr)) = expr.node,
+        let ExprBlock(ref else_block) = else_expr.node,
+        then_block.stmts.is_empty() && else_block.stmts.is_empty(),
+        let Some(ref then_expr) = then_block.expr,
+        let Some(ref else_value) = else_block.expr,
+        let Some((callee, then_args, else_args)) = same_callee(cx, then_expr, else_value)
+    ], {
+        let eq = SpanlessEq::new(cx);
+        let differing = (0..then_args.len())
+                            .filter(|&i| !eq.eq_expr(&then_args[i], &else_args[i]))
+                            .collect::<Vec<_>>();
+        if differing.len() != 1 {
+            return;
+        }
+
+        let args = (0..then_args.len())
+                       .map(|i| {
+                           if i == differing[0] {
+                               format!("if {} {{ {} }} else {{ {} }}",
+                                       snippet(cx, cond.span, ".."),
+                                       snippet(cx, then_args[i].span, ".."),
+                                       snippet(cx, else_args[i].span, ".."))
+                           } else {
+                               snippet(cx, then_args[i].span, "..").into_owned()
+                           }
+                       })
+                       .collect::<Vec<_>>()
+                       .join(", ");
+
+        span_lint_and_then(cx,
+                           IF_SAME_CALL_DIFFERENT_ARG,
+                           expr.span,
+                           "this `if` has branches which only differ in one argument",
+                           |db| {
+                               db.span_suggestion(expr.span, "consider moving the `if` into the argument",
+                                                  format!("{}({})", callee, args));
+                           });
+    }}
+}
+
+/// If both expressions call the same function or method, return the callee and both lists of
+/// arguments. The receiver of method calls must be the same and is not part of the arguments.
+fn same_callee<'e>(cx: &LateContext, lhs: &'e Expr, rhs: &'e Expr) -> Option<(String, &'e [P<Expr>], &'e [P<Expr>])> {
+    match (&lhs.node, &rhs.node) {
+        (&ExprCall(ref lfun, ref largs), &ExprCall(ref rfun, ref rargs)) => {
+            if largs.len() == rargs.len() && SpanlessEq::new(cx).ignore_fn().eq_expr(lfun, rfun) {
+                Some((snippet(cx, lfun.span, "..").into_owned(), largs, rargs))
+            } else {
+                None
+            }
+        }
+        (&ExprMethodCall(lname, ref ltys, ref largs), &ExprMethodCall(rname, ref rtys, ref rargs)) => {
+            if lname.node == rname.node && ltys.is_empty() && rtys.is_empty() && largs.len() == rargs.len() &&
+               SpanlessEq::new(cx).ignore_fn().eq_expr(&largs[0], &rargs[0]) {
+                Some((format!("{}.{}", snippet(cx, largs[0].span, ".."), lname.node), &largs[1..], &rargs[1..]))
+            } else {
+                None
+            }
+        }
+        _ => None,
+    }
+}
+
 /// Return the list of condition expressions and the list of blocks in a sequence of `if/else`.
 /// Eg. would return `([a, b], [c, d, e])` for the expression
 /// `if a { c } else if b { d } else { e }`.
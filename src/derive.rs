@@ -1,4 +1,6 @@
+use consts::{constant_simple, Constant};
 use rustc::lint::*;
+use rustc::middle::def::Def;
 use rustc::middle::subst::Subst;
 use rustc::middle::ty::TypeVariants;
 use rustc::middle::ty::fast_reject::simplify_type;
@@ -6,8 +8,9 @@ use rustc::middle::ty;
 use rustc_front::hir::*;
 use syntax::ast::{Attribute, MetaItemKind};
 use syntax::codemap::Span;
-use utils::{CLONE_TRAIT_PATH, HASH_PATH};
-use utils::{match_path, span_lint_and_then};
+use utils::{BTREEMAP_PATH, CLONE_TRAIT_PATH, DEFAULT_TRAIT_PATH, HASHMAP_PATH, HASH_PATH, LL_PATH, OPTION_PATH,
+            STRING_PATH, VEC_PATH};
+use utils::{match_path, match_type, span_help_and_lint, span_lint_and_then};
 
 /// **What it does:** This lint warns about deriving `Hash` but implementing `PartialEq`
 /// explicitly.
@@ -61,18 +64,46 @@ declare_lint! {
     "implementing `Clone` explicitly on `Copy` types"
 }
 
+/// **What it does:** This lint warns about manual `Default` implementations on structs which only
+/// set every field to its own default value.
+///
+/// **Why is this bad?** `#[derive(Default)]` does exactly the same thing with less code, and stays
+/// correct when fields are added.
+///
+/// **Known problems:** Generic types are ignored, as deriving would add `Default` bounds on the
+/// type parameters.
+///
+/// **Example:**
+/// ```rust
+/// struct Foo {
+///     bar: u32,
+///     baz: String,
+/// }
+///
+/// impl Default for Foo {
+///     fn default() -> Foo {
+///         Foo { bar: 0, baz: String::new() }
+///     }
+/// }
+/// ```
+declare_lint! {
+    pub DERIVABLE_DEFAULT,
+    Warn,
+    "implementing `Default` manually where `#[derive(Default)]` would do the same"
+}
+
 pub struct Derive;
 
 impl LintPass for Derive {
     fn get_lints(&self) -> LintArray {
-        lint_array!(EXPL_IMPL_CLONE_ON_COPY, DERIVE_HASH_XOR_EQ)
+        lint_array!(EXPL_IMPL_CLONE_ON_COPY, DERIVE_HASH_XOR_EQ, DERIVABLE_DEFAULT)
     }
 }
 
 impl LateLintPass for Derive {
     fn check_item(&mut self, cx: &LateContext, item: &Item) {
         if_let_chain! {[
-            let ItemImpl(_, _, _, Some(ref trait_ref), _, _) = item.node
+            let ItemImpl(_, _, ref generics, Some(ref trait_ref), _, ref impl_items) = item.node
         ], {
             let ty = cx.tcx.lookup_item_type(cx.tcx.map.local_def_id(item.id)).ty;
             let is_automatically_derived = item.attrs.iter().any(is_automatically_derived);
@@ -81,6 +112,7 @@ impl LateLintPass for Derive {
 
             if !is_automatically_derived {
                 check_copy_clone(cx, item, trait_ref, ty);
+                check_default(cx, item, trait_ref, generics, impl_items, ty);
             }
         }}
     }
@@ -174,11 +206,163 @@ fn check_copy_clone<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, item: &Item, trait_ref
                            item.span,
                            "you are implementing `Clone` explicitly on a `Copy` type",
                            |db| {
-                               db.span_note(item.span, "consider deriving `Clone` or removing `Copy`");
+                               if is_trivial_clone(cx, item) {
+                                   db.span_help(item.span,
+                                                "this implementation only copies `self`, consider using \
+                                                 `#[derive(Clone)]` instead");
+                               } else {
+                                   db.span_note(item.span, "consider deriving `Clone` or removing `Copy`");
+                               }
                            });
     }
 }
 
+/// Is this `Clone` implementation equivalent to a copy of `self`, ie. `*self` or a rebuild of
+/// `self` from its own fields?
+fn is_trivial_clone(cx: &LateContext, item: &Item) -> bool {
+    if let ItemImpl(_, _, _, _, _, ref impl_items) = item.node {
+        impl_items.iter().any(|impl_item| {
+            if_let_chain! {[
+                impl_item.name.as_str() == "clone",
+                let ImplItemKind::Method(_, ref block) = impl_item.node,
+                block.stmts.is_empty(),
+                let Some(ref ret) = block.expr
+            ], {
+                return match ret.node {
+                    ExprUnary(UnDeref, ref inner) => is_self(inner),
+                    ExprStruct(_, ref fields, None) => {
+                        fields.iter().all(|field| {
+                            match field.expr.node {
+                                ExprField(ref obj, ref name) => is_self(obj) && name.node == field.name.node,
+                                _ => false,
+                            }
+                        })
+                    }
+                    ExprCall(ref fun, ref args) => {
+                        is_struct_path(cx, fun) &&
+                        args.iter().enumerate().all(|(i, arg)| {
+                            match arg.node {
+                                ExprTupField(ref obj, ref index) => is_self(obj) && index.node == i,
+                                _ => false,
+                            }
+                        })
+                    }
+                    // unit structs
+                    ExprPath(..) => is_struct_path(cx, ret),
+                    _ => false,
+                };
+            }}
+
+            false
+        })
+    } else {
+        false
+    }
+}
+
+/// Is this expression the `self` path?
+fn is_self(expr: &Expr) -> bool {
+    if let ExprPath(None, ref path) = expr.node {
+        path.segments.len() == 1 && path.segments[0].identifier.name.as_str() == "self"
+    } else {
+        false
+    }
+}
+
+/// Does this path expression resolve to a struct (or its constructor)?
+fn is_struct_path(cx: &LateContext, expr: &Expr) -> bool {
+    cx.tcx.def_map.borrow().get(&expr.id).map_or(false, |d| {
+        if let Def::Struct(..) = d.full_def() {
+            true
+        } else {
+            false
+        }
+    })
+}
+
+/// Implementation of the `DERIVABLE_DEFAULT` lint.
+fn check_default(cx: &LateContext, item: &Item, trait_ref: &TraitRef, generics: &Generics, impl_items: &[ImplItem],
+                 ty: ty::Ty) {
+    if !match_path(&trait_ref.path, &DEFAULT_TRAIT_PATH) || !generics.ty_params.is_empty() {
+        return;
+    }
+
+    // enums have no natural default variant
+    match ty.sty {
+        TypeVariants::TyStruct(..) => (),
+        _ => return,
+    }
+
+    for impl_item in impl_items {
+        if_let_chain! {[
+            impl_item.name.as_str() == "default",
+            let ImplItemKind::Method(_, ref block) = impl_item.node,
+            block.stmts.is_empty(),
+            let Some(ref ret) = block.expr,
+            is_default_struct(cx, ret)
+        ], {
+            span_help_and_lint(cx,
+                               DERIVABLE_DEFAULT,
+                               item.span,
+                               "this `Default` implementation only sets every field to its default value",
+                               "consider using `#[derive(Default)]` on the type instead");
+        }}
+    }
+}
+
+/// Is this expression a struct or tuple struct literal where each field is a default value?
+fn is_default_struct(cx: &LateContext, expr: &Expr) -> bool {
+    match expr.node {
+        ExprStruct(_, ref fields, None) => fields.iter().all(|field| is_default_value(cx, &field.expr)),
+        ExprCall(ref fun, ref args) => {
+            // make sure this is a tuple struct constructor and not some function
+            !args.is_empty() && args.iter().all(|arg| is_default_value(cx, arg)) && is_struct_path(cx, fun)
+        }
+        _ => false,
+    }
+}
+
+/// Is this expression what the `Default` implementation of its type would return?
+fn is_default_value(cx: &LateContext, expr: &Expr) -> bool {
+    match expr.node {
+        ExprLit(_) => {
+            match constant_simple(expr) {
+                Some(Constant::Int(0, _, _)) | Some(Constant::Bool(false)) => true,
+                Some(Constant::Float(ref f, _)) => f.parse::<f64>().ok() == Some(0.0),
+                Some(Constant::Str(ref s, _)) => s.is_empty(),
+                _ => false,
+            }
+        }
+        ExprTup(ref exprs) => exprs.iter().all(|e| is_default_value(cx, e)),
+        ExprPath(None, ref path) => {
+            match_path(path, &["None"]) && match_type(cx, cx.tcx.expr_ty(expr), &OPTION_PATH)
+        }
+        ExprCall(ref fun, ref args) if args.is_empty() => {
+            if let ExprPath(None, ref path) = fun.node {
+                if match_path(path, &["default"]) {
+                    true
+                } else if match_path(path, &["new"]) {
+                    // only trust `new` of standard types whose `Default` is the same
+                    let ty = cx.tcx.expr_ty(expr);
+                    STD_NEW_IS_DEFAULT.iter().any(|path| match_type(cx, ty, path))
+                } else {
+                    false
+                }
+            } else {
+                false
+            }
+        }
+        _ => false,
+    }
+}
+
+/// Standard types whose `new()` is the same as their `default()`.
+const STD_NEW_IS_DEFAULT: [&'static [&'static str]; 5] = [&STRING_PATH,
+                                                          &VEC_PATH,
+                                                          &HASHMAP_PATH,
+                                                          &BTREEMAP_PATH,
+                                                          &LL_PATH];
+
 /// Checks for the `#[automatically_derived]` attribute all `#[derive]`d implementations have.
 fn is_automatically_derived(attr: &Attribute) -> bool {
     if let MetaItemKind::Word(ref word) = attr.node.value.node {
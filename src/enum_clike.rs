@@ -29,7 +29,7 @@ impl LintPass for EnumClikeUnportableVariant {
 }
 
 impl LateLintPass for EnumClikeUnportableVariant {
-    #[allow(cast_possible_truncation, cast_sign_loss)]
+    #[allow(cast_possible_truncation, cast_sign_loss, cast_lossless)]
     fn check_item(&mut self, cx: &LateContext, item: &Item) {
         if let ItemEnum(ref def, _) = item.node {
             for var in &def.variants {
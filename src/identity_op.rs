@@ -1,16 +1,17 @@
 use consts::{constant_simple, Constant, Sign};
 use rustc::lint::*;
 use rustc_front::hir::*;
+use rustc_front::util::is_comparison_binop;
 use syntax::codemap::Span;
-use utils::{span_lint, snippet, in_macro};
+use utils::{get_parent_expr, span_lint, snippet, in_macro};
 
-/// **What it does:** This lint checks for identity operations, e.g. `x + 0`.
+/// **What it does:** This lint checks for identity operations, e.g. `x + 0`, and for `x & 0`, which is always `0`.
 ///
 /// **Why is this bad?** This code can be removed without changing the meaning. So it just obscures what's going on. Delete it mercilessly.
 ///
 /// **Known problems:** None
 ///
-/// **Example:** `x / 1 + 0 * 1 - 0 | 0`
+/// **Example:** `x / 1 + 0 * 1 - 0 | 0 ^ 0`
 declare_lint! {
     pub IDENTITY_OP, Warn,
     "using identity operations, e.g. `x + 0` or `y / 1`"
@@ -45,6 +46,7 @@ impl LateLintPass for IdentityOp {
                 BiBitAnd => {
                     check(cx, left, -1, e.span, right.span);
                     check(cx, right, -1, e.span, left.span);
+                    check_zeroing(cx, e, left, right);
                 }
                 _ => (),
             }
@@ -69,3 +71,27 @@ fn check(cx: &LateContext, e: &Expr, m: i8, span: Span, arg: Span) {
         }
     }
 }
+
+/// `x & 0` is not an identity, but always `0`. Comparisons of such masks are handled by `BAD_BIT_MASK`.
+fn check_zeroing(cx: &LateContext, e: &Expr, left: &Expr, right: &Expr) {
+    if let Some(parent) = get_parent_expr(cx, e) {
+        if let ExprBinary(ref op, _, _) = parent.node {
+            if is_comparison_binop(op.node) {
+                return;
+            }
+        }
+    }
+    let is_zero = |e: &Expr| {
+        if let Some(Constant::Int(0, _, _)) = constant_simple(e) {
+            true
+        } else {
+            false
+        }
+    };
+    if is_zero(left) || is_zero(right) {
+        span_lint(cx,
+                  IDENTITY_OP,
+                  e.span,
+                  "this operation will always return `0`. Consider reducing it to `0`");
+    }
+}
@@ -0,0 +1,66 @@
+//! Checks for `write!(..).unwrap()` in functions which could propagate the error instead
+
+use rustc::lint::*;
+use rustc_front::hir::*;
+use utils::{RESULT_PATH, STRING_PATH, VEC_PATH};
+use utils::{get_enclosing_fn_ret_ty, is_expn_of, match_type, snippet, span_lint_and_then, walk_ptrs_ty};
+
+/// **What it does:** This lint checks for `.unwrap()` on the result of `write!` or `writeln!` in a function which
+/// itself returns a `Result`.
+///
+/// **Why is this bad?** The function could just as well pass the error on to its caller with `try!` instead of
+/// panicking.
+///
+/// **Known problems:** The error type of the function has to be convertible from the error of the writer.
+/// Writes to a `String` or a `Vec<u8>` are left to `INFALLIBLE_WRITE_UNWRAP`.
+///
+/// **Example:**
+///
+/// ```
+/// fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+///     writeln!(f, "{}", self.0).unwrap();
+///     Ok(())
+/// }
+/// ```
+declare_lint! {
+    pub WRITE_UNWRAP,
+    Allow,
+    "using `unwrap()` on the result of `write!` in a function returning a `Result`; suggests using `try!` instead"
+}
+
+#[derive(Copy, Clone)]
+pub struct WriteUnwrap;
+
+impl LintPass for WriteUnwrap {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(WRITE_UNWRAP)
+    }
+}
+
+impl LateLintPass for WriteUnwrap {
+    fn check_expr(&mut self, cx: &LateContext, expr: &Expr) {
+        if_let_chain! {[
+            let ExprMethodCall(ref name, _, ref unwrap_args) = expr.node,
+            name.node.as_str() == "unwrap",
+            let ExprMethodCall(ref write_name, _, ref write_args) = unwrap_args[0].node,
+            write_name.node.as_str() == "write_fmt",
+            // writing to these cannot fail, this is handled by `INFALLIBLE_WRITE_UNWRAP`
+            !match_type(cx, walk_ptrs_ty(cx.tcx.expr_ty(&write_args[0])), &STRING_PATH),
+            !match_type(cx, walk_ptrs_ty(cx.tcx.expr_ty(&write_args[0])), &VEC_PATH),
+            let Some(macro_span) = is_expn_of(cx, unwrap_args[0].span, "writeln")
+                                       .or_else(|| is_expn_of(cx, unwrap_args[0].span, "write")),
+            let Some(ret_ty) = get_enclosing_fn_ret_ty(cx, expr.id),
+            match_type(cx, ret_ty, &RESULT_PATH)
+        ], {
+            span_lint_and_then(cx,
+                               WRITE_UNWRAP,
+                               expr.span,
+                               "called `unwrap()` on the result of `write!` in a function returning a `Result`",
+                               |db| {
+                                   db.span_suggestion(expr.span,
+                                                      "consider passing the error on with `try!`",
+                                                      format!("try!({})", snippet(cx, macro_span, "..")));
+                               });
+        }}
+    }
+}
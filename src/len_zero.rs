@@ -66,8 +66,26 @@ impl LateLintPass for LenZero {
 
         if let ExprBinary(Spanned{node: cmp, ..}, ref left, ref right) = expr.node {
             match cmp {
-                BiEq => check_cmp(cx, expr.span, left, right, ""),
-                BiGt | BiNe => check_cmp(cx, expr.span, left, right, "!"),
+                BiEq => {
+                    check_cmp(cx, expr.span, left, right, "", 0);
+                    check_cmp(cx, expr.span, right, left, "", 0);
+                }
+                BiNe => {
+                    check_cmp(cx, expr.span, left, right, "!", 0);
+                    check_cmp(cx, expr.span, right, left, "!", 0);
+                }
+                BiGt => {
+                    // `x.len() > 0` or `1 > x.len()`
+                    check_cmp(cx, expr.span, left, right, "!", 0);
+                    check_cmp(cx, expr.span, right, left, "", 1);
+                }
+                BiLt => {
+                    // `0 < x.len()` or `x.len() < 1`
+                    check_cmp(cx, expr.span, right, left, "!", 0);
+                    check_cmp(cx, expr.span, left, right, "", 1);
+                }
+                BiGe => check_cmp(cx, expr.span, left, right, "!", 1), // `x.len() >= 1`
+                BiLe => check_cmp(cx, expr.span, right, left, "!", 1), // `1 <= x.len()`
                 _ => (),
             }
         }
@@ -136,26 +154,32 @@ fn is_self_sig(sig: &MethodSig) -> bool {
     }
 }
 
-fn check_cmp(cx: &LateContext, span: Span, left: &Expr, right: &Expr, op: &str) {
+/// Check a comparison of `method` to the literal `lit`, which is linted if `lit` is `compare_to`.
+fn check_cmp(cx: &LateContext, span: Span, method: &Expr, lit: &Expr, op: &str, compare_to: u64) {
     // check if we are in an is_empty() method
-    if let Some(name) = get_item_name(cx, left) {
+    if let Some(name) = get_item_name(cx, method) {
         if name.as_str() == "is_empty" {
             return;
         }
     }
-    match (&left.node, &right.node) {
-        (&ExprLit(ref lit), &ExprMethodCall(ref method, _, ref args)) |
-        (&ExprMethodCall(ref method, _, ref args), &ExprLit(ref lit)) => {
-            check_len_zero(cx, span, &method.node, args, lit, op)
-        }
-        _ => (),
+    if let (&ExprMethodCall(ref name, _, ref args), &ExprLit(ref lit)) = (&method.node, &lit.node) {
+        check_len_zero(cx, span, &name.node, args, lit, op, compare_to)
     }
 }
 
-fn check_len_zero(cx: &LateContext, span: Span, name: &Name, args: &[P<Expr>], lit: &Lit, op: &str) {
-    if let Spanned{node: LitKind::Int(0, _), ..} = *lit {
+fn check_len_zero(cx: &LateContext, span: Span, name: &Name, args: &[P<Expr>], lit: &Lit, op: &str,
+                  compare_to: u64) {
+    if let Spanned{node: LitKind::Int(value, _), ..} = *lit {
+        if value != compare_to {
+            return;
+        }
         if name.as_str() == "len" && args.len() == 1 && has_is_empty(cx, &args[0]) {
-            span_lint_and_then(cx, LEN_ZERO, span, "length comparison to zero", |db| {
+            let msg = if compare_to == 0 {
+                "length comparison to zero"
+            } else {
+                "length comparison to one"
+            };
+            span_lint_and_then(cx, LEN_ZERO, span, msg, |db| {
                 db.span_suggestion(span,
                                    "consider using `is_empty`",
                                    format!("{}{}.is_empty()", op, snippet(cx, args[0].span, "_")));
@@ -61,10 +61,12 @@ pub mod format;
 pub mod formatting;
 pub mod identity_op;
 pub mod if_not_else;
+pub mod io_unwrap;
 pub mod items_after_statements;
 pub mod len_zero;
 pub mod lifetimes;
 pub mod loops;
+pub mod map_capacity;
 pub mod map_clone;
 pub mod matches;
 pub mod methods;
@@ -84,6 +86,7 @@ pub mod precedence;
 pub mod print;
 pub mod ptr_arg;
 pub mod ranges;
+pub mod redundant_else;
 pub mod regex;
 pub mod returns;
 pub mod shadow;
@@ -173,26 +176,76 @@ pub fn plugin_registrar(reg: &mut Registry) {
     reg.register_early_lint_pass(box formatting::Formatting);
     reg.register_late_lint_pass(box swap::Swap);
     reg.register_early_lint_pass(box if_not_else::IfNotElse);
+    reg.register_late_lint_pass(box ptr_arg::ByteVecArg);
+    reg.register_late_lint_pass(box misc::InfallibleWriteUnwrap);
+    reg.register_late_lint_pass(box misc::CmpOptionNone);
+    reg.register_late_lint_pass(box misc::IntegerDivisionToFloat);
+    reg.register_late_lint_pass(box misc::NeedlessCloneArg);
+    reg.register_late_lint_pass(box strings::RepeatedCharPush);
+    reg.register_late_lint_pass(box misc::ExitInLibrary);
+    reg.register_late_lint_pass(box types::LargeTuplePass::new(4));
+    reg.register_late_lint_pass(box strings::SplitCollectIndex);
+    reg.register_late_lint_pass(box io_unwrap::WriteUnwrap);
+    reg.register_late_lint_pass(box types::MixedRefNesting);
+    reg.register_late_lint_pass(box strings::ManualStringFold);
+    reg.register_late_lint_pass(box misc::LetUnderscoreMustUse);
+    reg.register_late_lint_pass(box types::StaticMutRefReturn);
+    reg.register_late_lint_pass(box types::ManyBoolParams::new(2));
+    reg.register_late_lint_pass(box misc::NeedlessAbs);
+    reg.register_late_lint_pass(box redundant_else::RedundantElse::default());
+    reg.register_late_lint_pass(box misc::OrderingComparison);
+    reg.register_late_lint_pass(box types::RedundantTraitBound);
+    reg.register_late_lint_pass(box types::AlwaysSomeReturn);
+    reg.register_late_lint_pass(box misc::PowerOfTwoDivisor);
+    reg.register_late_lint_pass(box map_capacity::MapCapacity);
+    reg.register_late_lint_pass(box types::SingleUseTypeParam);
+    reg.register_late_lint_pass(box methods::ChainedCommandArgs::new(2));
+    reg.register_late_lint_pass(box misc::SizeOfLiteralComparison);
 
     reg.register_lint_group("clippy_pedantic", vec![
+        copies::IF_SAME_CALL_DIFFERENT_ARG,
         enum_glob_use::ENUM_GLOB_USE,
+        io_unwrap::WRITE_UNWRAP,
+        lifetimes::NEEDLESS_STATIC_BOUND,
+        loops::LEADING_CONTINUE,
+        map_capacity::MAP_WITHOUT_CAPACITY,
+        matches::IF_LET_IGNORED_ERR,
+        matches::MATCH_LITERALS_CONTAINS,
         matches::SINGLE_MATCH_ELSE,
+        methods::CHECKED_UNWRAP,
+        methods::FOLD_TO_SUM,
+        methods::INT_TO_FLOAT_SUM,
+        methods::OPTION_AS_REF_DEREF,
         methods::OPTION_UNWRAP_USED,
         methods::RESULT_UNWRAP_USED,
+        methods::STRING_TO_OWNED,
         methods::WRONG_PUB_SELF_CONVENTION,
+        misc::EXIT_IN_LIBRARY,
+        misc::LET_UNDERSCORE_MUST_USE,
+        misc::POWER_OF_TWO_DIVISOR,
+        misc::SIZE_OF_LITERAL_COMPARISON,
         mut_mut::MUT_MUT,
         mutex_atomic::MUTEX_INTEGER,
         print::PRINT_STDOUT,
         print::USE_DEBUG,
+        ptr_arg::BYTE_VEC_ARG,
         shadow::SHADOW_REUSE,
         shadow::SHADOW_SAME,
         shadow::SHADOW_UNRELATED,
+        strings::MANUAL_STRING_FOLD,
         strings::STRING_ADD,
         strings::STRING_ADD_ASSIGN,
+        types::ALWAYS_SOME_RETURN,
+        types::CAST_LOSSLESS,
         types::CAST_POSSIBLE_TRUNCATION,
+        types::CAST_POSSIBLE_TRUNCATION_TRYFROM,
         types::CAST_POSSIBLE_WRAP,
         types::CAST_PRECISION_LOSS,
         types::CAST_SIGN_LOSS,
+        types::LARGE_TUPLE,
+        types::MANY_BOOL_PARAMS,
+        types::MIXED_REF_NESTING,
+        types::SINGLE_USE_TYPE_PARAM,
         unicode::NON_ASCII_LITERAL,
         unicode::UNICODE_NOT_NFC,
     ]);
@@ -211,6 +264,7 @@ pub fn plugin_registrar(reg: &mut Registry) {
         copies::IFS_SAME_COND,
         copies::MATCH_SAME_ARMS,
         cyclomatic_complexity::CYCLOMATIC_COMPLEXITY,
+        derive::DERIVABLE_DEFAULT,
         derive::DERIVE_HASH_XOR_EQ,
         derive::EXPL_IMPL_CLONE_ON_COPY,
         drop_ref::DROP_REF,
@@ -237,37 +291,74 @@ pub fn plugin_registrar(reg: &mut Registry) {
         loops::FOR_LOOP_OVER_OPTION,
         loops::FOR_LOOP_OVER_RESULT,
         loops::ITER_NEXT_LOOP,
+        loops::MANUAL_FILTER_COLLECT,
+        loops::MANUAL_LAST,
+        loops::MANUAL_MEMCPY,
+        loops::NEEDLESS_CONTINUE,
         loops::NEEDLESS_RANGE_LOOP,
+        loops::ONCE_LOOP,
         loops::REVERSE_RANGE_LOOP,
         loops::UNUSED_COLLECT,
+        loops::WHILE_CONST_TRUE,
         loops::WHILE_LET_LOOP,
         loops::WHILE_LET_ON_ITERATOR,
         map_clone::MAP_CLONE,
+        matches::IF_AS_MATCH_GUARD,
+        matches::MATCH_AS_BOOL,
         matches::MATCH_BOOL,
         matches::MATCH_OVERLAPPING_ARM,
         matches::MATCH_REF_PATS,
+        matches::REDUNDANT_PATTERN_MATCHING,
         matches::SINGLE_MATCH,
+        methods::BOXED_ROUND_TRIP,
+        methods::BYTES_AS_CHARS,
+        methods::BYTES_COUNT_TO_LEN,
+        methods::CHAINED_COMMAND_ARGS,
+        methods::CHARS_COUNT_EMPTY,
         methods::CHARS_NEXT_CMP,
         methods::CLONE_DOUBLE_REF,
+        methods::CLONE_INTO_ITER_NEXT,
         methods::CLONE_ON_COPY,
+        methods::DOUBLE_OWNING_CONVERSION,
+        methods::DRAIN_COLLECT,
         methods::EXTEND_FROM_SLICE,
+        methods::FILTER_MAP_IF,
+        methods::FILTER_MAP_UNWRAP,
         methods::FILTER_NEXT,
+        methods::IDENTITY_MAP,
+        methods::ITER_CLONED_COLLECT,
+        methods::ITER_COUNT_ZERO,
+        methods::ITER_NTH_UNWRAP,
+        methods::ITER_NTH_ZERO,
+        methods::LINEAR_KEY_SEARCH,
+        methods::NEEDLESS_COLLECT,
         methods::NEW_RET_NO_SELF,
+        methods::NOOP_ARITHMETIC_METHOD,
         methods::OK_EXPECT,
         methods::OPTION_MAP_UNWRAP_OR,
         methods::OPTION_MAP_UNWRAP_OR_ELSE,
         methods::OR_FUN_CALL,
+        methods::REVERSED_NEXT,
         methods::SEARCH_IS_SOME,
         methods::SHOULD_IMPLEMENT_TRAIT,
         methods::SINGLE_CHAR_PATTERN,
         methods::STR_TO_STRING,
+        methods::STRING_AS_BYTES_TO_VEC,
         methods::STRING_TO_STRING,
+        methods::UNNECESSARY_LAZY_EVALUATION,
+        methods::VEC_REMOVE_ZERO,
         methods::WRONG_SELF_CONVENTION,
         minmax::MIN_MAX,
         misc::CMP_NAN,
+        misc::CMP_OPTION_NONE,
         misc::CMP_OWNED,
         misc::FLOAT_CMP,
+        misc::INFALLIBLE_WRITE_UNWRAP,
+        misc::INTEGER_DIVISION_TO_FLOAT,
         misc::MODULO_ONE,
+        misc::NEEDLESS_ABS,
+        misc::NEEDLESS_CLONE_ARG,
+        misc::ORDERING_COMPARISON,
         misc::REDUNDANT_PATTERN,
         misc::TOPLEVEL_REF_ARG,
         misc::USED_UNDERSCORE_BINDING,
@@ -286,23 +377,31 @@ pub fn plugin_registrar(reg: &mut Registry) {
         panic::PANIC_PARAMS,
         precedence::PRECEDENCE,
         ptr_arg::PTR_ARG,
+        ptr_arg::PTR_RET,
         ranges::RANGE_STEP_BY_ZERO,
         ranges::RANGE_ZIP_WITH_LEN,
+        redundant_else::REDUNDANT_ELSE,
         regex::INVALID_REGEX,
         regex::REGEX_MACRO,
         regex::TRIVIAL_REGEX,
         returns::LET_AND_RETURN,
         returns::NEEDLESS_RETURN,
+        strings::REPEATED_CHAR_PUSH,
+        strings::SPLIT_COLLECT_INDEX,
         strings::STRING_LIT_AS_BYTES,
         swap::ALMOST_SWAPPED,
         swap::MANUAL_SWAP,
         temporary_assignment::TEMPORARY_ASSIGNMENT,
+        transmute::TRANSMUTE_INT_TO_BOOL,
         transmute::USELESS_TRANSMUTE,
         types::ABSURD_EXTREME_COMPARISONS,
         types::BOX_VEC,
+        types::CAST_LEN_TRUNCATION,
         types::CHAR_LIT_AS_U8,
         types::LET_UNIT_VALUE,
         types::LINKEDLIST,
+        types::REDUNDANT_TRAIT_BOUND,
+        types::STATIC_MUT_REF_RETURN,
         types::TYPE_COMPLEXITY,
         types::UNIT_CMP,
         unicode::ZERO_WIDTH_SPACE,
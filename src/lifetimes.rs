@@ -1,11 +1,12 @@
 use reexport::*;
 use rustc::lint::*;
 use rustc::middle::def::Def;
+use rustc::middle::ty::adjustment::AutoAdjustment;
 use rustc_front::hir::*;
-use rustc_front::intravisit::{Visitor, walk_ty, walk_ty_param_bound, walk_fn_decl, walk_generics};
+use rustc_front::intravisit::{Visitor, walk_expr, walk_ty, walk_ty_param_bound, walk_fn_decl, walk_generics};
 use std::collections::{HashSet, HashMap};
 use syntax::codemap::Span;
-use utils::{in_external_macro, span_lint};
+use utils::{in_external_macro, span_lint, span_note_and_lint};
 
 /// **What it does:** This lint checks for lifetime annotations which can be removed by relying on lifetime elision.
 ///
@@ -34,25 +35,43 @@ declare_lint! {
     "unused lifetimes in function definitions"
 }
 
+/// **What it does:** This lint checks for `T: 'static` bounds on generic parameters of functions
+/// where `T` only ever appears behind references.
+///
+/// **Why is this bad?** The bound prevents callers from passing borrowed data while the function
+/// does not need to keep anything beyond the call.
+///
+/// **Known problems:** The body is only checked for obvious uses of `T` (explicit mentions, casts
+/// and coercions), calling a function which itself requires `T: 'static` is not detected.
+///
+/// **Example:** `fn show<T: Debug + 'static>(x: &T) { println!("{:?}", x) }`
+declare_lint! {
+    pub NEEDLESS_STATIC_BOUND,
+    Allow,
+    "`T: 'static` bounds on generic parameters which are only borrowed in the function"
+}
+
 #[derive(Copy,Clone)]
 pub struct LifetimePass;
 
 impl LintPass for LifetimePass {
     fn get_lints(&self) -> LintArray {
-        lint_array!(NEEDLESS_LIFETIMES, UNUSED_LIFETIMES)
+        lint_array!(NEEDLESS_LIFETIMES, UNUSED_LIFETIMES, NEEDLESS_STATIC_BOUND)
     }
 }
 
 impl LateLintPass for LifetimePass {
     fn check_item(&mut self, cx: &LateContext, item: &Item) {
-        if let ItemFn(ref decl, _, _, _, ref generics, _) = item.node {
+        if let ItemFn(ref decl, _, _, _, ref generics, ref body) = item.node {
             check_fn_inner(cx, decl, None, &generics, item.span);
+            check_static_bounds(cx, decl, &generics, body);
         }
     }
 
     fn check_impl_item(&mut self, cx: &LateContext, item: &ImplItem) {
-        if let ImplItemKind::Method(ref sig, _) = item.node {
+        if let ImplItemKind::Method(ref sig, ref body) = item.node {
             check_fn_inner(cx, &sig.decl, Some(&sig.explicit_self), &sig.generics, item.span);
+            check_static_bounds(cx, &sig.decl, &sig.generics, body);
         }
     }
 
@@ -361,3 +380,115 @@ fn report_extra_lifetimes(cx: &LateContext, func: &FnDecl, generics: &Generics,
         span_lint(cx, UNUSED_LIFETIMES, v, "this lifetime isn't used in the function definition");
     }
 }
+
+/// Implementation of `NEEDLESS_STATIC_BOUND`.
+fn check_static_bounds(cx: &LateContext, decl: &FnDecl, generics: &Generics, body: &Block) {
+    for param in generics.ty_params.iter() {
+        let static_bound = param.bounds
+                                .iter()
+                                .filter_map(|bound| {
+                                    match *bound {
+                                        RegionTyParamBound(ref lt) if lt.name.as_str() == "'static" => Some(lt),
+                                        _ => None,
+                                    }
+                                })
+                                .next();
+
+        if let Some(lt) = static_bound {
+            let mut visitor = TyParamUseVisitor {
+                name: param.name,
+                refs: 0,
+                found: false,
+                by_value: false,
+            };
+            for arg in &decl.inputs {
+                visitor.visit_ty(&arg.ty);
+            }
+            if !visitor.found || visitor.by_value {
+                continue;
+            }
+
+            if let Return(ref ret) = decl.output {
+                visitor.visit_ty(ret);
+                if visitor.by_value {
+                    continue;
+                }
+            }
+
+            let mut visitor = BodyUseVisitor {
+                cx: cx,
+                name: param.name,
+                used: false,
+            };
+            visitor.visit_block(body);
+            if visitor.used {
+                continue;
+            }
+
+            span_note_and_lint(cx,
+                               NEEDLESS_STATIC_BOUND,
+                               lt.span,
+                               &format!("this `'static` bound is not needed, `{}` is only borrowed in this function",
+                                        param.name),
+                               param.span,
+                               "callers cannot pass borrowed data because of this bound");
+        }
+    }
+}
+
+/// Looks for a type parameter in a type, remembering whether it appears outside of references.
+struct TyParamUseVisitor {
+    name: Name,
+    refs: usize,
+    found: bool,
+    by_value: bool,
+}
+
+impl<'v> Visitor<'v> for TyParamUseVisitor {
+    fn visit_ty(&mut self, ty: &'v Ty) {
+        match ty.node {
+            TyRptr(..) => {
+                self.refs += 1;
+                walk_ty(self, ty);
+                self.refs -= 1;
+            }
+            TyPath(None, ref path) if path.segments.len() == 1 && path.segments[0].identifier.name == self.name => {
+                self.found = true;
+                if self.refs == 0 {
+                    self.by_value = true;
+                }
+            }
+            _ => walk_ty(self, ty),
+        }
+    }
+}
+
+/// Looks for uses of a type parameter in a function body which might need it to be `'static`.
+struct BodyUseVisitor<'a, 'tcx: 'a> {
+    cx: &'a LateContext<'a, 'tcx>,
+    name: Name,
+    used: bool,
+}
+
+impl<'v, 'a, 'tcx> Visitor<'v> for BodyUseVisitor<'a, 'tcx> {
+    fn visit_ty(&mut self, ty: &'v Ty) {
+        if let TyPath(None, ref path) = ty.node {
+            if path.segments.len() == 1 && path.segments[0].identifier.name == self.name {
+                self.used = true;
+            }
+        }
+        walk_ty(self, ty);
+    }
+
+    fn visit_expr(&mut self, expr: &'v Expr) {
+        if let ExprCast(..) = expr.node {
+            self.used = true;
+        }
+        if let Some(&AutoAdjustment::AdjustDerefRef(ref adj)) = self.cx.tcx.tables.borrow().adjustments.get(&expr.id) {
+            if adj.unsize.is_some() {
+                self.used = true;
+            }
+        }
+        walk_expr(self, expr);
+    }
+}
@@ -1,3 +1,4 @@
+use consts::{constant, Constant};
 use reexport::*;
 use rustc::front::map::Node::NodeBlock;
 use rustc::lint::*;
@@ -7,14 +8,16 @@ use rustc::middle::def::Def;
 use rustc::middle::region::CodeExtent;
 use rustc::middle::ty;
 use rustc_front::hir::*;
-use rustc_front::intravisit::{Visitor, walk_expr, walk_block, walk_decl};
+use rustc_front::intravisit::{Visitor, walk_expr, walk_block, walk_decl, walk_pat};
 use std::borrow::Cow;
-use std::collections::HashMap;
-use syntax::ast;
+use std::collections::{HashMap, HashSet};
+use syntax::ast::{self, RangeLimits};
+use syntax::codemap::Span;
 
-use utils::{snippet, span_lint, get_parent_expr, match_trait_method, match_type, in_external_macro,
+use utils::{snippet, span_lint, get_parent_expr, match_trait_method, match_type, in_external_macro, in_macro,
             span_help_and_lint, is_integer_literal, get_enclosing_block, span_lint_and_then,
-            unsugar_range, walk_ptrs_ty};
+            span_note_and_lint, unsugar_range, walk_ptrs_ty, recover_for_loop, get_single_expr, match_path,
+            SpanlessEq};
 use utils::{BTREEMAP_PATH, HASHMAP_PATH, LL_PATH, OPTION_PATH, RESULT_PATH, VEC_PATH};
 use utils::UnsugaredRange;
 
@@ -203,6 +206,169 @@ declare_lint! {
     "looping on a map using `iter` when `keys` or `values` would do"
 }
 
+/// **What it does:** This lint checks for `for` loops over a range which only copy elements from
+/// one slice to another, as in `for i in 0..n { dst[i] = src[i]; }`.
+///
+/// **Why is this bad?** `clone_from_slice` does the same in one line, and more efficiently for
+/// `Copy` types.
+///
+/// **Known problems:** The suggested ranges are only simplified when both the bound and the offset
+/// are integer literals, e.g. a loop over `n..m` writing to `dst[i - n]` gives `dst[n - n..m - n]`.
+///
+/// **Example:**
+/// ```rust
+/// for i in 0..src.len() {
+///     dst[i + 64] = src[i];
+/// }
+/// ```
+/// could be replaced by
+/// ```rust
+/// dst[64..src.len() + 64].clone_from_slice(&src[..src.len()]);
+/// ```
+declare_lint! {
+    pub MANUAL_MEMCPY,
+    Warn,
+    "manually copying items between slices"
+}
+
+/// **What it does:** This lint checks for `for` loops which only push the elements matching a
+/// condition into a `Vec` created just before the loop.
+///
+/// **Why is this bad?** `filter` and `collect` say the same in one line.
+///
+/// **Known problems:** The closure given to `filter` gets a reference to the element, so the
+/// condition may need to be adapted.
+///
+/// **Example:**
+/// ```rust
+/// let mut evens = Vec::new();
+/// for x in v {
+///     if x % 2 == 0 {
+///         evens.push(x);
+///     }
+/// }
+/// ```
+/// could be replaced by
+/// ```rust
+/// let evens: Vec<_> = v.into_iter().filter(|x| x % 2 == 0).collect();
+/// ```
+declare_lint! {
+    pub MANUAL_FILTER_COLLECT,
+    Warn,
+    "manually pushing the elements matching a condition into a `Vec`"
+}
+
+/// **What it does:** This lint checks for loop bodies starting with `if cond { continue }`.
+///
+/// **Why is this bad?** The guard states when an iteration is skipped, wrapping the rest of the
+/// body in `if !cond { .. }` states when it is run instead, which is often easier to follow.
+///
+/// **Known problems:** This adds a level of nesting, which may be less readable with long bodies.
+///
+/// **Example:**
+/// ```rust
+/// for x in v {
+///     if x % 2 != 0 {
+///         continue;
+///     }
+///     println!("{}", x);
+/// }
+/// ```
+/// could be written as
+/// ```rust
+/// for x in v {
+///     if x % 2 == 0 {
+///         println!("{}", x);
+///     }
+/// }
+/// ```
+declare_lint! {
+    pub LEADING_CONTINUE,
+    Allow,
+    "a loop body starting with `if cond { continue }`, which could be an `if` around the rest of the body"
+}
+
+/// **What it does:** This lint checks for unlabeled `continue` as the last statement of a loop
+/// body, or of an `if` which is itself the last statement.
+///
+/// **Why is this bad?** The loop continues with the next iteration anyway.
+///
+/// **Known problems:** Labeled `continue`s are not linted.
+///
+/// **Example:**
+/// ```rust
+/// for x in v {
+///     println!("{}", x);
+///     continue;
+/// }
+/// ```
+declare_lint! {
+    pub NEEDLESS_CONTINUE,
+    Warn,
+    "a `continue` as the last statement of a loop body, which does nothing"
+}
+
+/// **What it does:** This lint checks for `loop { .. break; }` where the final `break` is the
+/// only way out of the loop and nothing `continue`s it.
+///
+/// **Why is this bad?** The body is executed exactly once, the `loop` only adds a level of
+/// nesting and makes the reader look for another iteration.
+///
+/// **Known problems:** None
+///
+/// **Example:**
+/// ```rust
+/// loop {
+///     do_something();
+///     break;
+/// }
+/// ```
+declare_lint! {
+    pub ONCE_LOOP,
+    Warn,
+    "a `loop` which unconditionally `break`s at the end of its first iteration"
+}
+
+/// **What it does:** This lint checks for `for` loops which only store each element in an
+/// `Option` declared as `None` just before the loop.
+///
+/// **Why is this bad?** This is what `Iterator::last` does.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// let mut last = None;
+/// for x in v {
+///     last = Some(x);
+/// }
+/// ```
+/// could be replaced by
+/// ```rust
+/// let last = v.into_iter().last();
+/// ```
+declare_lint! {
+    pub MANUAL_LAST,
+    Warn,
+    "manually looking for the last element of an iterator"
+}
+
+/// **What it does:** This lint checks for `while` loops whose condition is always `true`, like
+/// `while true { .. }`. Conditions using a named constant, like `while RUNNING`, are not linted as
+/// such a constant is probably a configuration flag.
+///
+/// **Why is this bad?** `loop { .. }` says the same thing and lets the compiler know the loop
+/// only exits with a `break`.
+///
+/// **Known problems:** The literal `while true` is also linted by the compiler's `while_true`.
+///
+/// **Example:** `while true { .. }` could be `loop { .. }`
+declare_lint! {
+    pub WHILE_CONST_TRUE,
+    Warn,
+    "`while` loops with an always `true` condition; suggests using `loop` instead"
+}
+
 #[derive(Copy, Clone)]
 pub struct LoopsPass;
 
@@ -217,7 +383,14 @@ impl LintPass for LoopsPass {
                     EXPLICIT_COUNTER_LOOP,
                     EMPTY_LOOP,
                     WHILE_LET_ON_ITERATOR,
-                    FOR_KV_MAP)
+                    FOR_KV_MAP,
+                    MANUAL_MEMCPY,
+                    MANUAL_FILTER_COLLECT,
+                    LEADING_CONTINUE,
+                    NEEDLESS_CONTINUE,
+                    ONCE_LOOP,
+                    MANUAL_LAST,
+                    WHILE_CONST_TRUE)
     }
 }
 
@@ -226,10 +399,17 @@ impl LateLintPass for LoopsPass {
         if let Some((pat, arg, body)) = recover_for_loop(expr) {
             check_for_loop(cx, pat, arg, body, expr);
         }
+        if let Some(block) = loop_body(expr) {
+            check_leading_continue(cx, block);
+            check_needless_continue(cx, block);
+        }
+        if let ExprWhile(ref cond, _, label) = expr.node {
+            check_while_const_true(cx, expr, cond, label);
+        }
         // check for `loop { if let {} else break }` that could be `while let`
         // (also matches an explicit "match" instead of "if let")
         // (even if the "match" or "if let" is used for declaration)
-        if let ExprLoop(ref block, _) = expr.node {
+        if let ExprLoop(ref block, label) = expr.node {
             // also check for empty `loop {}` statements
             if block.stmts.is_empty() && block.expr.is_none() {
                 span_lint(cx,
@@ -239,6 +419,8 @@ impl LateLintPass for LoopsPass {
                            `std::thread::sleep(..);` to the loop body.");
             }
 
+            check_once_loop(cx, expr, block, label);
+
             // extract the expression from the first statement (if any) in a block
             let inner_stmt_expr = extract_expr_from_first_stmt(block);
             // or extract the first expression (if any) from the block
@@ -286,13 +468,26 @@ impl LateLintPass for LoopsPass {
                        match_trait_method(cx, match_expr, &["core", "iter", "Iterator"]) &&
                        lhs_constructor.identifier.name.as_str() == "Some" &&
                        !is_iterator_used_after_while_let(cx, iter_expr) {
-                        let iterator = snippet(cx, method_args[0].span, "_");
                         let loop_var = snippet(cx, pat_args[0].span, "_");
-                        span_help_and_lint(cx,
+                        // `let mut it = ..; while let Some(x) = it.next()` can use the initializer directly
+                        let (lo, iterator) = match iterator_declared_before(cx, iter_expr, expr) {
+                            Some((let_span, init)) => (let_span.lo, snippet(cx, init.span, "_")),
+                            None => (expr.span.lo, snippet(cx, iter_expr.span, "_")),
+                        };
+                        let span = Span {
+                            lo: lo,
+                            hi: match_expr.span.hi,
+                            expn_id: expr.span.expn_id,
+                        };
+                        span_lint_and_then(cx,
                                            WHILE_LET_ON_ITERATOR,
                                            expr.span,
                                            "this loop could be written as a `for` loop",
-                                           &format!("try\nfor {} in {} {{...}}", loop_var, iterator));
+                                           |db| {
+                                               db.span_suggestion(span,
+                                                                  "try",
+                                                                  format!("for {} in {}", loop_var, iterator));
+                                           });
                     }
                 }
             }
@@ -315,12 +510,321 @@ impl LateLintPass for LoopsPass {
     }
 }
 
+fn check_while_const_true(cx: &LateContext, expr: &Expr, cond: &Expr, label: Option<Ident>) {
+    if in_macro(cx, expr.span) {
+        return;
+    }
+    // `while RUNNING` is probably a deliberate configuration flag
+    let mut visitor = PathVisitor { found: false };
+    visitor.visit_expr(cond);
+    if visitor.found {
+        return;
+    }
+    if let Some((Constant::Bool(true), _)) = constant(cx, cond) {
+        let span = Span { hi: cond.span.hi, ..expr.span };
+        let sugg = match label {
+            Some(label) => format!("{}: loop", label.name),
+            None => "loop".to_owned(),
+        };
+        span_lint_and_then(cx,
+                           WHILE_CONST_TRUE,
+                           span,
+                           "this `while` condition is always `true`",
+                           |db| {
+                               db.span_suggestion(span, "use `loop` instead", sugg);
+                           });
+    }
+}
+
+/// Look for any path in an expression.
+struct PathVisitor {
+    found: bool,
+}
+
+impl<'v> Visitor<'v> for PathVisitor {
+    fn visit_expr(&mut self, expr: &'v Expr) {
+        if let ExprPath(..) = expr.node {
+            self.found = true;
+        }
+        walk_expr(self, expr);
+    }
+}
+
 fn check_for_loop(cx: &LateContext, pat: &Pat, arg: &Expr, body: &Expr, expr: &Expr) {
     check_for_loop_range(cx, pat, arg, body, expr);
     check_for_loop_reverse_range(cx, arg, expr);
     check_for_loop_arg(cx, pat, arg, expr);
     check_for_loop_explicit_counter(cx, arg, body, expr);
     check_for_loop_over_map_kv(cx, pat, arg, body, expr);
+    check_for_loop_memcpy(cx, pat, arg, body, expr);
+    check_for_loop_filter_collect(cx, pat, arg, body, expr);
+    check_for_loop_manual_last(cx, pat, arg, body, expr);
+}
+
+/// An index of the form `var`, `var + offset` or `var - offset`.
+struct Offset {
+    value: String,
+    negate: bool,
+}
+
+/// Match `expr` against the index forms of `Offset`, where `var` is the loop variable.
+fn get_offset(cx: &LateContext, expr: &Expr, var: Ident) -> Option<Offset> {
+    fn is_var(expr: &Expr, var: Ident) -> bool {
+        if let ExprPath(None, ref path) = expr.node {
+            path.segments.len() == 1 && path.segments[0].identifier == var
+        } else {
+            false
+        }
+    }
+
+    /// The offset must not depend on the loop variable itself.
+    fn offset(cx: &LateContext, expr: &Expr, var: Ident, negate: bool) -> Option<Offset> {
+        let mut visitor = UsedVisitor {
+            var: var,
+            used: false,
+        };
+        walk_expr(&mut visitor, expr);
+        if visitor.used || is_var(expr, var) {
+            None
+        } else {
+            Some(Offset {
+                value: snippet(cx, expr.span, "..").into_owned(),
+                negate: negate,
+            })
+        }
+    }
+
+    match expr.node {
+        ExprPath(..) if is_var(expr, var) => {
+            Some(Offset {
+                value: String::new(),
+                negate: false,
+            })
+        }
+        ExprBinary(op, ref lhs, ref rhs) => {
+            match op.node {
+                BiAdd if is_var(lhs, var) => offset(cx, rhs, var, false),
+                BiAdd if is_var(rhs, var) => offset(cx, lhs, var, false),
+                BiSub if is_var(lhs, var) => offset(cx, rhs, var, true),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Check for `for i in a..b { dst[i] = src[i]; }` loops which could be `clone_from_slice`.
+fn check_for_loop_memcpy(cx: &LateContext, pat: &Pat, arg: &Expr, body: &Expr, expr: &Expr) {
+    if_let_chain! {[
+        let PatKind::Ident(_, ref ident, _) = pat.node,
+        let Some(UnsugaredRange { start: Some(ref start), end: Some(ref end), limits: RangeLimits::HalfOpen }) =
+            unsugar_range(arg),
+        let Some(assign) = get_single_expr(body),
+        let ExprAssign(ref lhs, ref rhs) = assign.node,
+        let ExprIndex(ref dst, ref dst_idx) = lhs.node,
+        let ExprIndex(ref src, ref src_idx) = rhs.node,
+        is_slice_like(cx, dst),
+        is_slice_like(cx, src),
+        !SpanlessEq::new(cx).eq_expr(dst, src),
+        let Some(dst_offset) = get_offset(cx, dst_idx, ident.node),
+        let Some(src_offset) = get_offset(cx, src_idx, ident.node)
+    ], {
+        let print_bound = |bound: &Expr, offset: &Offset| -> String {
+            let bound = snippet(cx, bound.span, "..");
+            // fold `10 + 5` and `10 - 10`
+            if let (Ok(b), Ok(o)) = (bound.parse::<u64>(), offset.value.parse::<u64>()) {
+                let folded = if offset.negate {
+                    b.checked_sub(o)
+                } else {
+                    b.checked_add(o)
+                };
+                if let Some(folded) = folded {
+                    return folded.to_string();
+                }
+            }
+            if offset.value.is_empty() {
+                bound.into_owned()
+            } else if bound == "0" && !offset.negate {
+                offset.value.clone()
+            } else {
+                format!("{} {} {}", bound, if offset.negate { "-" } else { "+" }, offset.value)
+            }
+        };
+        let print_range = |offset: &Offset| -> String {
+            let start = print_bound(start, offset);
+            let end = print_bound(end, offset);
+            if start == "0" {
+                format!("..{}", end)
+            } else {
+                format!("{}..{}", start, end)
+            }
+        };
+
+        span_lint_and_then(cx,
+                           MANUAL_MEMCPY,
+                           expr.span,
+                           "it looks like you're manually copying between slices",
+                           |db| {
+                               db.span_suggestion(expr.span,
+                                                  "try replacing the loop by",
+                                                  format!("{}[{}].clone_from_slice(&{}[{}])",
+                                                          snippet(cx, dst.span, "_"),
+                                                          print_range(&dst_offset),
+                                                          snippet(cx, src.span, "_"),
+                                                          print_range(&src_offset)));
+                           });
+    }}
+}
+
+/// Check for `let mut v = Vec::new(); for x in y { if p { v.push(x) } }` loops which could be
+/// `filter` + `collect`.
+fn check_for_loop_filter_collect(cx: &LateContext, pat: &Pat, arg: &Expr, body: &Expr, expr: &Expr) {
+    if_let_chain! {[
+        let PatKind::Ident(BindByValue(_), ref ident, None) = pat.node,
+        let Some(if_expr) = get_single_expr(body),
+        let ExprIf(ref cond, ref then, None) = if_expr.node,
+        then.stmts.len() + then.expr.iter().count() == 1,
+        let Some(push) = extract_first_expr(then),
+        let ExprMethodCall(ref name, _, ref push_args) = push.node,
+        name.node.as_str() == "push",
+        push_args.len() == 2,
+        let ExprPath(None, ref pushed) = push_args[1].node,
+        pushed.segments.len() == 1 && pushed.segments[0].identifier == ident.node,
+        let ExprPath(None, ref vec_path) = push_args[0].node,
+        vec_path.segments.len() == 1,
+        match_type(cx, cx.tcx.expr_ty(&push_args[0]), &VEC_PATH),
+        let Some(vec_id) = var_def_id(cx, &push_args[0]),
+        is_vec_created_before(cx, vec_id, expr)
+    ], {
+        // the condition must not look at the `Vec` being filled
+        let mut visitor = UsedVisitor {
+            var: vec_path.segments[0].identifier,
+            used: false,
+        };
+        walk_expr(&mut visitor, cond);
+        if visitor.used {
+            return;
+        }
+
+        span_note_and_lint(cx,
+                           MANUAL_FILTER_COLLECT,
+                           expr.span,
+                           "it looks like you're manually filtering elements into a `Vec`",
+                           expr.span,
+                           &format!("consider using `let {}: Vec<_> = {}.into_iter().filter(|{}| {}).collect();` \
+                                     instead, the closure gets a reference to the element",
+                                    vec_path.segments[0].identifier.name,
+                                    snippet(cx, arg.span, "_"),
+                                    ident.node.name,
+                                    snippet(cx, cond.span, "..")));
+    }}
+}
+
+/// Is the statement just before the loop `expr` the declaration of the `Vec` `vec_id` as
+/// `Vec::new()` or `Vec::with_capacity(_)`?
+fn is_vec_created_before(cx: &LateContext, vec_id: NodeId, expr: &Expr) -> bool {
+    init_before_loop(cx, vec_id, expr).map_or(false, is_new_vec)
+}
+
+/// Is this `Vec::new()` or `Vec::with_capacity(_)`?
+fn is_new_vec(init: &Expr) -> bool {
+    if let ExprCall(ref fun, _) = init.node {
+        if let ExprPath(None, ref path) = fun.node {
+            return match_path(path, &["Vec", "new"]) || match_path(path, &["Vec", "with_capacity"]);
+        }
+    }
+    false
+}
+
+/// Get the initializer of the local `id` if it is declared by the statement just before the loop
+/// `expr`.
+fn init_before_loop<'c>(cx: &'c LateContext, id: NodeId, expr: &Expr) -> Option<&'c Expr> {
+    let map = &cx.tcx.map;
+    let parent_scope = map.get_enclosing_scope(expr.id).and_then(|id| map.get_enclosing_scope(id));
+    if let Some(NodeBlock(block)) = parent_scope.map(|id| map.get(id)) {
+        let loop_index = block.stmts.iter().position(|stmt| {
+            match stmt.node {
+                // the loop itself may be wrapped by its desugaring
+                StmtExpr(ref e, _) | StmtSemi(ref e, _) => e.span.lo <= expr.span.lo && expr.span.hi <= e.span.hi,
+                _ => false,
+            }
+        });
+        if_let_chain! {[
+            let Some(loop_index) = loop_index,
+            loop_index > 0,
+            let StmtDecl(ref decl, _) = block.stmts[loop_index - 1].node,
+            let DeclLocal(ref local) = decl.node,
+            local.pat.id == id,
+            let Some(ref init) = local.init
+        ], {
+            return Some(init);
+        }}
+    }
+    None
+}
+
+/// Check for `for x in iter { last = Some(x); }` where `last` is declared as `None` just before
+/// the loop.
+fn check_for_loop_manual_last(cx: &LateContext, pat: &Pat, arg: &Expr, body: &Expr, expr: &Expr) {
+    if_let_chain! {[
+        let PatKind::Ident(BindByValue(_), ref ident, None) = pat.node,
+        let Some(assign) = get_single_expr(body),
+        let ExprAssign(ref last, ref value) = assign.node,
+        let ExprPath(None, ref last_path) = last.node,
+        last_path.segments.len() == 1,
+        let ExprCall(ref some, ref some_args) = value.node,
+        let ExprPath(None, ref some_path) = some.node,
+        match_path(some_path, &["Some"]),
+        some_args.len() == 1,
+        let Some(cloned) = stored_element(&some_args[0], ident.node),
+        let Some(last_id) = var_def_id(cx, last),
+        let Some(init) = init_before_loop(cx, last_id, expr),
+        let ExprPath(None, ref init_path) = init.node,
+        match_path(init_path, &["None"])
+    ], {
+        // an owned element does not need to be cloned anymore
+        let cloned = match cx.tcx.pat_ty(pat).sty {
+            ty::TyRef(..) if cloned => ".cloned()",
+            _ => "",
+        };
+        let iter = match arg.node {
+            ExprAddrOf(MutImmutable, ref inner) => format!("{}.iter()", snippet(cx, inner.span, "_")),
+            ExprAddrOf(MutMutable, ref inner) => format!("{}.iter_mut()", snippet(cx, inner.span, "_")),
+            _ => format!("{}.into_iter()", snippet(cx, arg.span, "_")),
+        };
+        span_note_and_lint(cx,
+                           MANUAL_LAST,
+                           expr.span,
+                           "it looks like you're manually looking for the last element",
+                           expr.span,
+                           &format!("consider using `let {} = {}.last(){};` instead",
+                                    last_path.segments[0].identifier.name,
+                                    iter,
+                                    cloned));
+    }}
+}
+
+/// Is this expression the loop variable `var` or a clone of it? Returns whether it is cloned.
+fn stored_element(expr: &Expr, var: Ident) -> Option<bool> {
+    let (element, cloned) = match expr.node {
+        ExprMethodCall(ref name, _, ref args) if name.node.as_str() == "clone" && args.len() == 1 => (&*args[0], true),
+        _ => (expr, false),
+    };
+    if let ExprPath(None, ref path) = element.node {
+        if path.segments.len() == 1 && path.segments[0].identifier == var {
+            return Some(cloned);
+        }
+    }
+    None
+}
+
+/// Is this expression an array, a slice or a `Vec`, possibly behind references?
+fn is_slice_like(cx: &LateContext, expr: &Expr) -> bool {
+    let ty = walk_ptrs_ty(cx.tcx.expr_ty(expr));
+    match ty.sty {
+        ty::TyArray(..) | ty::TySlice(..) => true,
+        _ => match_type(cx, ty, &VEC_PATH),
+    }
 }
 
 /// Check for looping over a range and then indexing a sequence with it.
@@ -370,15 +874,19 @@ fn check_for_loop_range(cx: &LateContext, pat: &Pat, arg: &Expr, body: &Expr, ex
                     "".into()
                 };
 
+                let elem = fresh_binding_name(body, "item");
+
+                // no `span_suggestion`, the body would still index with the loop variable
                 if visitor.nonindex {
                     span_lint(cx,
                               NEEDLESS_RANGE_LOOP,
                               expr.span,
-                              &format!("the loop variable `{}` is used to index `{}`. Consider using `for ({}, \
-                                        item) in {}.iter().enumerate(){}{}` or similar iterators",
+                              &format!("the loop variable `{}` is used to index `{}`. Consider using `for ({}, {}) in \
+                                        {}.iter().enumerate(){}{}` or similar iterators",
                                        ident.node.name,
                                        indexed,
                                        ident.node.name,
+                                       elem,
                                        indexed,
                                        take,
                                        skip));
@@ -393,9 +901,10 @@ fn check_for_loop_range(cx: &LateContext, pat: &Pat, arg: &Expr, body: &Expr, ex
                               NEEDLESS_RANGE_LOOP,
                               expr.span,
                               &format!("the loop variable `{}` is only used to index `{}`. \
-                                        Consider using `for item in {}` or similar iterators",
+                                        Consider using `for {} in {}` or similar iterators",
                                        ident.node.name,
                                        indexed,
+                                       elem,
                                        repl));
                 }
             }
@@ -403,6 +912,41 @@ fn check_for_loop_range(cx: &LateContext, pat: &Pat, arg: &Expr, body: &Expr, ex
     }
 }
 
+/// Return a name based on `base` which is not used for any variable in `body`.
+fn fresh_binding_name(body: &Expr, base: &str) -> String {
+    let mut collector = NameCollector(HashSet::new());
+    walk_expr(&mut collector, body);
+
+    let mut name = base.to_owned();
+    let mut i = 1;
+    while collector.0.contains(&name) {
+        name = format!("{}{}", base, i);
+        i += 1;
+    }
+    name
+}
+
+/// Collect the names of all local bindings and single-segment paths.
+struct NameCollector(HashSet<String>);
+
+impl<'v> Visitor<'v> for NameCollector {
+    fn visit_expr(&mut self, expr: &'v Expr) {
+        if let ExprPath(None, ref path) = expr.node {
+            if path.segments.len() == 1 {
+                self.0.insert(path.segments[0].identifier.name.as_str().to_string());
+            }
+        }
+        walk_expr(self, expr);
+    }
+
+    fn visit_pat(&mut self, pat: &'v Pat) {
+        if let PatKind::Ident(_, ref ident, _) = pat.node {
+            self.0.insert(ident.node.name.as_str().to_string());
+        }
+        walk_pat(self, pat);
+    }
+}
+
 fn is_len_call(expr: &Expr, var: &Name) -> bool {
     if_let_chain! {[
         let ExprMethodCall(method, _, ref len_args) = expr.node,
@@ -644,30 +1188,6 @@ impl<'a> Visitor<'a> for UsedVisitor {
     }
 }
 
-/// Recover the essential nodes of a desugared for loop:
-/// `for pat in arg { body }` becomes `(pat, arg, body)`.
-fn recover_for_loop(expr: &Expr) -> Option<(&Pat, &Expr, &Expr)> {
-    if_let_chain! {
-        [
-            let ExprMatch(ref iterexpr, ref arms, _) = expr.node,
-            let ExprCall(_, ref iterargs) = iterexpr.node,
-            iterargs.len() == 1 && arms.len() == 1 && arms[0].guard.is_none(),
-            let ExprLoop(ref block, _) = arms[0].body.node,
-            block.stmts.is_empty(),
-            let Some(ref loopexpr) = block.expr,
-            let ExprMatch(_, ref innerarms, MatchSource::ForLoopDesugar) = loopexpr.node,
-            innerarms.len() == 2 && innerarms[0].pats.len() == 1,
-            let PatKind::TupleStruct(_, Some(ref somepats)) = innerarms[0].pats[0].node,
-            somepats.len() == 1
-        ], {
-            return Some((&somepats[0],
-                         &iterargs[0],
-                         &innerarms[0].body));
-        }
-    }
-    None
-}
-
 struct VarVisitor<'v, 't: 'v> {
     cx: &'v LateContext<'v, 't>, // context reference
     var: Name, // var name to look for as index
@@ -731,6 +1251,43 @@ fn is_iterator_used_after_while_let(cx: &LateContext, iter_expr: &Expr) -> bool
     visitor.var_used_after_while_let
 }
 
+/// If the iterator of a `while let` loop is a local declared by the statement right before the
+/// loop, return the span of that declaration and its initializer.
+fn iterator_declared_before<'c>(cx: &'c LateContext, iter_expr: &Expr, loop_expr: &Expr) -> Option<(Span, &'c Expr)> {
+    let def_id = match var_def_id(cx, iter_expr) {
+        Some(id) => id,
+        None => return None,
+    };
+    let block = match get_enclosing_block(cx, def_id) {
+        Some(block) => block,
+        None => return None,
+    };
+    for (i, stmt) in block.stmts.iter().enumerate() {
+        if_let_chain! {[
+            let StmtDecl(ref decl, _) = stmt.node,
+            let DeclLocal(ref local) = decl.node,
+            local.pat.id == def_id,
+            let Some(ref init) = local.init
+        ], {
+            let next_span = match block.stmts.get(i + 1) {
+                Some(next) => next.span,
+                None => {
+                    match block.expr {
+                        Some(ref next) => next.span,
+                        None => return None,
+                    }
+                }
+            };
+            return if next_span.lo <= loop_expr.span.lo && loop_expr.span.hi <= next_span.hi {
+                Some((stmt.span, &**init))
+            } else {
+                None
+            };
+        }}
+    }
+    None
+}
+
 struct VarUsedAfterLoopVisitor<'v, 't: 'v> {
     cx: &'v LateContext<'v, 't>,
     def_id: NodeId,
@@ -752,6 +1309,77 @@ impl<'v, 't> Visitor<'v> for VarUsedAfterLoopVisitor<'v, 't> {
     }
 }
 
+/// Lint `loop`s ending with a `break` which is their only exit and which are never continued.
+fn check_once_loop(cx: &LateContext, expr: &Expr, block: &Block, label: Option<Ident>) {
+    let label = label.map(|label| label.name);
+
+    // the final `break`, and the statements before it
+    let (last, stmts) = match block.expr {
+        Some(ref expr) => (&**expr, &block.stmts[..]),
+        None => {
+            match block.stmts.last().map(|stmt| &stmt.node) {
+                Some(&StmtSemi(ref expr, _)) => (&**expr, &block.stmts[..block.stmts.len() - 1]),
+                _ => return,
+            }
+        }
+    };
+    match last.node {
+        ExprBreak(target) if target.map_or(true, |target| Some(target.node.name) == label) => (),
+        _ => return,
+    }
+    if in_external_macro(cx, expr.span) {
+        return;
+    }
+
+    let mut visitor = LoopExitVisitor {
+        label: label,
+        depth: 0,
+        found: false,
+    };
+    for stmt in stmts {
+        visitor.visit_stmt(stmt);
+    }
+    if !visitor.found {
+        span_help_and_lint(cx,
+                           ONCE_LOOP,
+                           expr.span,
+                           "this loop never loops, its body is executed exactly once",
+                           "remove the `loop` and the final `break`");
+    }
+}
+
+/// Look for `break`s and `continue`s of a given loop in its body.
+struct LoopExitVisitor {
+    /// The label of the loop, if any.
+    label: Option<Name>,
+    /// The number of loops nested in the loop we are looking at.
+    depth: usize,
+    found: bool,
+}
+
+impl<'v> Visitor<'v> for LoopExitVisitor {
+    fn visit_expr(&mut self, expr: &'v Expr) {
+        match expr.node {
+            ExprBreak(target) | ExprAgain(target) => {
+                let exits = match target {
+                    Some(target) => Some(target.node.name) == self.label,
+                    None => self.depth == 0,
+                };
+                if exits {
+                    self.found = true;
+                }
+            }
+            ExprLoop(..) | ExprWhile(..) => {
+                self.depth += 1;
+                walk_expr(self, expr);
+                self.depth -= 1;
+            }
+            // `break` and `continue` cannot cross closures
+            ExprClosure(..) => (),
+            _ => walk_expr(self, expr),
+        }
+    }
+}
 
 /// Return true if the type of expr is one that provides IntoIterator impls
 /// for &T and &mut T, such as Vec.
@@ -813,6 +1441,132 @@ fn extract_first_expr(block: &Block) -> Option<&Expr> {
     }
 }
 
+/// Get the body of a `for`, `while`, `while let` or `loop` loop as written by the user.
+pub fn loop_body(expr: &Expr) -> Option<&Block> {
+    if let Some((_, _, body)) = recover_for_loop(expr) {
+        return if let ExprBlock(ref block) = body.node {
+            Some(block)
+        } else {
+            None
+        };
+    }
+    match expr.node {
+        // `for` and `while let` are desugared to a `loop` with a `match`, this never matches
+        // anything linted on user loops
+        ExprWhile(_, ref block, _) | ExprLoop(ref block, _) => Some(block),
+        ExprMatch(_, ref arms, MatchSource::WhileLetDesugar) => {
+            if let ExprBlock(ref block) = arms[0].body.node {
+                Some(block)
+            } else {
+                None
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Lint loop bodies starting with `if cond { continue }`.
+fn check_leading_continue(cx: &LateContext, block: &Block) {
+    if_let_chain! {[
+        // there must be something left to put in the `if`
+        block.stmts.len() > 1 || (!block.stmts.is_empty() && block.expr.is_some()),
+        let StmtExpr(ref guard, _) = block.stmts[0].node,
+        let ExprIf(ref cond, ref then, None) = guard.node,
+        is_continue_block(then),
+        !in_external_macro(cx, guard.span)
+    ], {
+        let cond = match cond.node {
+            ExprUnary(UnNot, ref inner) => snippet(cx, inner.span, "..").into_owned(),
+            ExprPath(..) | ExprCall(..) | ExprMethodCall(..) | ExprField(..) => {
+                format!("!{}", snippet(cx, cond.span, ".."))
+            }
+            _ => format!("!({})", snippet(cx, cond.span, "..")),
+        };
+        span_help_and_lint(cx,
+                           LEADING_CONTINUE,
+                           guard.span,
+                           "this loop body starts with a `continue` guard",
+                           &format!("consider putting the rest of the body in an `if` instead: `if {} {{ .. }}`",
+                                    cond));
+    }}
+}
+
+/// Lint unlabeled `continue`s at the end of a loop body, possibly in a final `if`.
+fn check_needless_continue(cx: &LateContext, block: &Block) {
+    let last = match block.expr {
+        Some(ref expr) => expr,
+        None => {
+            match block.stmts.last().map(|stmt| &stmt.node) {
+                Some(&StmtExpr(ref expr, _)) |
+                Some(&StmtSemi(ref expr, _)) => expr,
+                _ => return,
+            }
+        }
+    };
+    if in_external_macro(cx, last.span) {
+        return;
+    }
+    match last.node {
+        ExprAgain(None) => {
+            span_help_and_lint(cx,
+                               NEEDLESS_CONTINUE,
+                               last.span,
+                               "this `continue` is needless, it is the last statement of the loop body",
+                               "remove it");
+        }
+        ExprIf(_, ref then, ref els) => {
+            let help = if !is_continue_block(then) {
+                "remove it"
+            } else if els.is_some() {
+                "consider inverting the condition, putting the `else` block in the `if` block"
+            } else {
+                "remove the `if`, it does nothing but evaluate its condition"
+            };
+            check_needless_continue_in_if_block(cx, then, help);
+            if let Some(ref els) = *els {
+                if let ExprBlock(ref els) = els.node {
+                    check_needless_continue_in_if_block(cx, els, "remove it");
+                }
+            }
+        }
+        _ => (),
+    }
+}
+
+fn check_needless_continue_in_if_block(cx: &LateContext, block: &Block, help: &str) {
+    let last = match (block.stmts.last().map(|stmt| &stmt.node), &block.expr) {
+        (_, &Some(ref expr)) => expr,
+        (Some(&StmtSemi(ref expr, _)), &None) => expr,
+        _ => return,
+    };
+    if let ExprAgain(None) = last.node {
+        span_help_and_lint(cx,
+                           NEEDLESS_CONTINUE,
+                           last.span,
+                           "this `continue` is needless, the loop body ends after this `if`",
+                           help);
+    }
+}
+
+/// Return true if the block only contains an unlabeled `continue`.
+fn is_continue_block(block: &Block) -> bool {
+    let expr = match (block.stmts.len(), &block.expr) {
+        (0, &Some(ref expr)) => expr,
+        (1, &None) => {
+            match block.stmts[0].node {
+                StmtSemi(ref expr, _) => expr,
+                _ => return false,
+            }
+        }
+        _ => return false,
+    };
+    if let ExprAgain(None) = expr.node {
+        true
+    } else {
+        false
+    }
+}
+
 /// Return true if expr contains a single break expr (maybe within a block).
 fn is_break_expr(expr: &Expr) -> bool {
     match expr.node {
@@ -0,0 +1,138 @@
+use rustc::lint::*;
+use rustc::middle::ty;
+use rustc_front::hir::*;
+use syntax::ast::Name;
+use utils::{HASHMAP_PATH, VEC_PATH};
+use utils::{match_type, recover_for_loop, snippet, span_lint_and_then, walk_ptrs_ty};
+
+/// **What it does:** This lint checks for `HashMap::new()` bindings immediately followed by a
+/// `for` loop which only inserts into the map while iterating over a collection of known length.
+///
+/// **Why is this bad?** The map will be reallocated and rehashed several times while it grows.
+/// `HashMap::with_capacity(..)` allocates enough room up front.
+///
+/// **Known problems:** The inserted keys could be duplicates, in which case the map ends up
+/// smaller than the collection and some memory is wasted.
+///
+/// **Example:**
+/// ```rust
+/// let mut m = HashMap::new();
+/// for x in &v {
+///     m.insert(x, 0);
+/// }
+/// ```
+declare_lint! {
+    pub MAP_WITHOUT_CAPACITY,
+    Allow,
+    "creating a `HashMap` with `new()` and filling it from a collection of known length"
+}
+
+#[derive(Copy,Clone)]
+pub struct MapCapacity;
+
+impl LintPass for MapCapacity {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(MAP_WITHOUT_CAPACITY)
+    }
+}
+
+impl LateLintPass for MapCapacity {
+    fn check_block(&mut self, cx: &LateContext, block: &Block) {
+        for (i, stmt) in block.stmts.iter().enumerate() {
+            // the loop is either the next statement or the tail expression of the block
+            let next = match block.stmts.get(i + 1).map(|next| &next.node) {
+                Some(&StmtExpr(ref expr, _)) |
+                Some(&StmtSemi(ref expr, _)) => expr,
+                Some(_) => continue,
+                None => {
+                    if let Some(ref expr) = block.expr {
+                        expr
+                    } else {
+                        return;
+                    }
+                }
+            };
+
+            if_let_chain! {[
+                // let mut m = HashMap::new();
+                let StmtDecl(ref decl, _) = stmt.node,
+                let DeclLocal(ref local) = decl.node,
+                let PatKind::Ident(BindByValue(MutMutable), ref map_name, None) = local.pat.node,
+                let Some(ref init) = local.init,
+                let ExprCall(ref fun, ref args) = init.node,
+                args.is_empty(),
+                let ExprPath(None, ref path) = fun.node,
+                path.segments.last().map_or(false, |segment| segment.identifier.name.as_str() == "new"),
+                match_type(cx, cx.tcx.expr_ty(init), &HASHMAP_PATH),
+
+                // for x in v { m.insert(..); }
+                let Some((_, arg, body)) = recover_for_loop(next),
+                let ExprBlock(ref body) = body.node,
+                only_inserts(body, map_name.node.name),
+                let Some(len_expr) = known_len(cx, arg)
+            ], {
+                let fun = snippet(cx, fun.span, "HashMap::new");
+                span_lint_and_then(cx,
+                                   MAP_WITHOUT_CAPACITY,
+                                   local.span,
+                                   "this map is filled from a collection of known length right after being created",
+                                   |db| {
+                                       db.span_suggestion(init.span,
+                                                          "consider allocating enough room up front",
+                                                          format!("{}with_capacity({}.len())",
+                                                                  &fun[..fun.len() - "new".len()],
+                                                                  snippet(cx, len_expr.span, "..")));
+                                   });
+            }}
+        }
+    }
+}
+
+/// Check that a loop body only contains `map.insert(..);` statements.
+fn only_inserts(body: &Block, map: Name) -> bool {
+    body.expr.is_none() && !body.stmts.is_empty() &&
+    body.stmts.iter().all(|stmt| {
+        if_let_chain! {[
+            let StmtSemi(ref expr, _) = stmt.node,
+            let ExprMethodCall(ref name, _, ref args) = expr.node,
+            name.node.as_str() == "insert",
+            args.len() == 3,
+            let ExprPath(None, ref path) = args[0].node,
+            path.segments.len() == 1 && path.segments[0].identifier.name == map
+        ], {
+            return true;
+        }}
+        false
+    })
+}
+
+/// Get the expression whose `len()` gives the number of iterations of a `for` loop over `arg`, if
+/// it is a local or a field holding a collection of known length.
+fn known_len<'e>(cx: &LateContext, arg: &'e Expr) -> Option<&'e Expr> {
+    let collection = match arg.node {
+        ExprMethodCall(ref name, _, ref args) if args.len() == 1 &&
+                                                 ["iter", "iter_mut", "into_iter"].contains(&&*name.node.as_str()) => {
+            &*args[0]
+        }
+        ExprAddrOf(_, ref inner) => &**inner,
+        _ => arg,
+    };
+
+    // the collection is evaluated a second time in the suggestion, only do this for plain places
+    match collection.node {
+        ExprPath(..) | ExprField(..) | ExprTupField(..) => (),
+        _ => return None,
+    }
+
+    let ty = walk_ptrs_ty(cx.tcx.expr_ty(collection));
+    let has_len = match ty.sty {
+        ty::TySlice(_) | ty::TyArray(..) => true,
+        _ => match_type(cx, ty, &VEC_PATH) || match_type(cx, ty, &HASHMAP_PATH),
+    };
+
+    if has_len {
+        Some(collection)
+    } else {
+        None
+    }
+}
@@ -1,4 +1,5 @@
 use rustc::lint::*;
+use rustc::middle::ty;
 use rustc_front::hir::*;
 use utils::{CLONE_PATH, OPTION_PATH};
 use utils::{is_adjusted, match_path, match_trait_method, match_type, snippet, span_help_and_lint, walk_ptrs_ty,
@@ -10,7 +11,7 @@ use utils::{is_adjusted, match_path, match_trait_method, match_type, snippet, sp
 ///
 /// **Known problems:** None
 ///
-/// **Example:** `x.map(|e| e.clone());`
+/// **Example:** `x.map(|e| e.clone());` or `x.map(ToOwned::to_owned)`
 declare_lint! {
     pub MAP_CLONE, Warn,
     "using `.map(|x| x.clone())` to clone an iterator or option's contents (recommends \
@@ -65,7 +66,7 @@ impl LateLintPass for MapClonePass {
                         }
                     }
                     ExprPath(_, ref path) => {
-                        if match_path(path, &CLONE_PATH) {
+                        if match_path(path, &CLONE_PATH) || is_to_owned_clone(cx, path, &args[1]) {
                             let type_name = get_type_name(cx, expr, &args[0]).unwrap_or("_");
                             span_help_and_lint(cx,
                                                MAP_CLONE,
@@ -106,6 +107,22 @@ fn get_type_name(cx: &LateContext, expr: &Expr, arg: &Expr) -> Option<&'static s
     }
 }
 
+/// Is `path` `ToOwned::to_owned`, used on a type for which it is `clone` (i.e. not `str` or a slice)?
+fn is_to_owned_clone(cx: &LateContext, path: &Path, fun: &Expr) -> bool {
+    if !match_path(path, &["ToOwned", "to_owned"]) {
+        return false;
+    }
+    match cx.tcx.expr_ty(fun).fn_args().0.get(0).map(|ty| &ty.sty) {
+        Some(&ty::TyRef(_, ty::TypeAndMut { ty: inner, .. })) => {
+            match inner.sty {
+                ty::TyStr | ty::TySlice(_) => false,
+                _ => true,
+            }
+        }
+        _ => false,
+    }
+}
+
 fn get_arg_name(pat: &Pat) -> Option<Ident> {
     match pat.node {
         PatKind::Ident(_, ident, None) => Some(ident.node),
@@ -1,16 +1,20 @@
+use consts::{constant_simple, Constant};
 use rustc::lint::*;
 use rustc::middle::const_eval::ConstVal::{Int, Uint};
 use rustc::middle::const_eval::EvalHint::ExprTypeChecked;
 use rustc::middle::const_eval::{eval_const_expr_partial, ConstVal};
 use rustc::middle::ty;
 use rustc_front::hir::*;
+use rustc_front::intravisit::{Visitor, walk_expr, walk_pat};
 use std::cmp::Ordering;
-use syntax::ast::LitKind;
+use std::collections::HashSet;
+use syntax::ast::{LitKind, Name};
 use syntax::codemap::Span;
 use utils::{COW_PATH, OPTION_PATH, RESULT_PATH};
 use utils::{match_type, snippet, span_lint, span_note_and_lint, span_lint_and_then, in_external_macro, expr_block};
+use utils::{span_help_and_lint, SpanlessEq};
 
-/// **What it does:** This lint checks for matches with a single arm where an `if let` will usually suffice.
+/// **What it does:** This lint checks for matches with a single arm where an `if let` will usually suffice. A guard on the arm becomes an `if` nested in the `if let`.
 ///
 /// **Why is this bad?** Just readability – `if let` nests less than a `match`.
 ///
@@ -109,12 +113,129 @@ declare_lint! {
     pub MATCH_OVERLAPPING_ARM, Warn, "a match has overlapping arms"
 }
 
+/// **What it does:** This lint checks for `if let Some(_) = x` and similar patterns where the
+/// matched value is not bound, for `Option` and `Result`.
+///
+/// **Why is this bad?** It is more concise and clear to just use the `is_some()`, `is_none()`,
+/// `is_ok()` or `is_err()` methods.
+///
+/// **Known problems:** None
+///
+/// **Example:**
+///
+/// ```
+/// if let Some(_) = x {
+///     foo();
+/// }
+/// ```
+declare_lint! {
+    pub REDUNDANT_PATTERN_MATCHING, Warn,
+    "using `if let` with a wildcard pattern on an `Option` or `Result`; recommends `is_some()`, \
+     `is_ok()` etc. instead"
+}
+
+/// **What it does:** This lint checks for matches with a binding arm whose body is an `if` on
+/// the bound value, and a wildcard arm which does the same as the `else` branch.
+///
+/// **Why is this bad?** The condition can be moved to a match guard, which removes the duplicated
+/// `else` branch and a level of nesting.
+///
+/// **Known problems:** None
+///
+/// **Example:**
+/// ```
+/// match x {
+///     Some(y) => if y > 5 { foo() } else { bar() },
+///     _ => bar(),
+/// }
+/// ```
+/// could be
+/// ```
+/// match x {
+///     Some(y) if y > 5 => foo(),
+///     _ => bar(),
+/// }
+/// ```
+declare_lint! {
+    pub IF_AS_MATCH_GUARD, Warn,
+    "a match arm whose body is an `if` on the bound value, which could be a match guard"
+}
+
+/// **What it does:** This lint checks for matches on literal patterns whose arms only produce
+/// `true` or `false`.
+///
+/// **Why is this bad?** A comparison says the same thing in one line.
+///
+/// **Known problems:** None
+///
+/// **Example:**
+/// ```
+/// match x {
+///     1 | 2 => true,
+///     _ => false,
+/// }
+/// ```
+/// could be
+/// ```
+/// x == 1 || x == 2
+/// ```
+declare_lint! {
+    pub MATCH_AS_BOOL, Warn,
+    "a match on literals producing only boolean literals; recommends a comparison instead"
+}
+
+/// **What it does:** This lint checks for matches of several integer or character literals to
+/// `true` and everything else to `false`.
+///
+/// **Why is this bad?** This is a membership test, which `[..].contains(..)` spells out directly.
+///
+/// **Known problems:** None
+///
+/// **Example:**
+/// ```
+/// match x {
+///     1 | 2 | 3 => true,
+///     _ => false,
+/// }
+/// ```
+/// could be `[1, 2, 3].contains(&x)`
+declare_lint! {
+    pub MATCH_LITERALS_CONTAINS, Allow,
+    "a match of several literals to booleans; recommends `[..].contains(..)` instead"
+}
+
+/// **What it does:** This lint checks for `if let Ok(x) = res { .. }` without an `else` block.
+///
+/// **Why is this bad?** The error is silently discarded, which is sometimes a bug. Handling it
+/// explicitly, or a comment in an empty `else` block, shows that it was considered.
+///
+/// **Known problems:** Ignoring the error is often fine, hence this lint is `Allow` by default.
+///
+/// **Example:**
+/// ```
+/// if let Ok(file) = File::open(path) {
+///     read(file);
+/// }
+/// ```
+declare_lint! {
+    pub IF_LET_IGNORED_ERR, Allow,
+    "an `if let Ok(..) = ..` without an `else` block, which silently ignores the error"
+}
+
 #[allow(missing_copy_implementations)]
 pub struct MatchPass;
 
 impl LintPass for MatchPass {
     fn get_lints(&self) -> LintArray {
-        lint_array!(SINGLE_MATCH, MATCH_REF_PATS, MATCH_BOOL, SINGLE_MATCH_ELSE)
+        lint_array!(SINGLE_MATCH,
+                    MATCH_REF_PATS,
+                    MATCH_BOOL,
+                    MATCH_AS_BOOL,
+                    MATCH_LITERALS_CONTAINS,
+                    IF_LET_IGNORED_ERR,
+                    SINGLE_MATCH_ELSE,
+                    REDUNDANT_PATTERN_MATCHING,
+                    IF_AS_MATCH_GUARD)
     }
 }
 
@@ -126,21 +247,32 @@ impl LateLintPass for MatchPass {
         if let ExprMatch(ref ex, ref arms, MatchSource::Normal) = expr.node {
             check_single_match(cx, ex, arms, expr);
             check_match_bool(cx, ex, arms, expr);
+            check_match_as_bool(cx, ex, arms, expr);
             check_overlapping_arms(cx, ex, arms);
+            check_if_as_match_guard(cx, arms);
         }
         if let ExprMatch(ref ex, ref arms, source) = expr.node {
             check_match_ref_pats(cx, ex, arms, source, expr);
         }
+        if let ExprMatch(ref ex, ref arms, MatchSource::IfLetDesugar { .. }) = expr.node {
+            check_redundant_pattern_matching(cx, ex, arms, expr);
+        }
+        if let ExprMatch(ref ex, ref arms, MatchSource::IfLetDesugar { contains_else_clause: false }) = expr.node {
+            check_if_let_ignored_err(cx, ex, arms, expr);
+        }
     }
 }
 
 #[cfg_attr(rustfmt, rustfmt_skip)]
 fn check_single_match(cx: &LateContext, ex: &Expr, arms: &[Arm], expr: &Expr) {
     if arms.len() == 2 &&
-      arms[0].pats.len() == 1 && arms[0].guard.is_none() &&
+      arms[0].pats.len() == 1 &&
       arms[1].pats.len() == 1 && arms[1].guard.is_none() {
         let els = if is_unit_expr(&arms[1].body) {
             None
+        } else if arms[0].guard.is_some() {
+            // the `else` would also have to be run when the guard fails
+            return;
         } else if let ExprBlock(_) = arms[1].body.node {
             // matches with blocks that contain statements are prettier as `if let + else`
             Some(&*arms[1].body)
@@ -175,7 +307,7 @@ fn check_single_match_single_pattern(cx: &LateContext, ex: &Expr, arms: &[Arm],
                                                   format!("if let {} = {} {}{}",
                                                           snippet(cx, arms[0].pats[0].span, ".."),
                                                           snippet(cx, ex.span, ".."),
-                                                          expr_block(cx, &arms[0].body, None, ".."),
+                                                          single_arm_block(cx, &arms[0]),
                                                           els_str));
                            });
     }
@@ -223,13 +355,25 @@ fn check_single_match_opt_like(cx: &LateContext, ex: &Expr, arms: &[Arm], expr:
                                                       format!("if let {} = {} {}{}",
                                                               snippet(cx, arms[0].pats[0].span, ".."),
                                                               snippet(cx, ex.span, ".."),
-                                                              expr_block(cx, &arms[0].body, None, ".."),
+                                                              single_arm_block(cx, &arms[0]),
                                                               els_str));
                                });
         }
     }
 }
 
+/// Get the block of the `if let` replacing a single match arm, with its guard as a nested `if`.
+fn single_arm_block(cx: &LateContext, arm: &Arm) -> String {
+    match arm.guard {
+        Some(ref guard) => {
+            format!("{{ if {} {} }}",
+                    snippet(cx, guard.span, ".."),
+                    expr_block(cx, &arm.body, None, ".."))
+        }
+        None => expr_block(cx, &arm.body, None, "..").into_owned(),
+    }
+}
+
 fn check_match_bool(cx: &LateContext, ex: &Expr, arms: &[Arm], expr: &Expr) {
     // type of expression == bool
     if cx.tcx.expr_ty(ex).sty == ty::TyBool {
@@ -285,6 +429,88 @@ fn check_match_bool(cx: &LateContext, ex: &Expr, arms: &[Arm], expr: &Expr) {
     }
 }
 
+fn check_match_as_bool(cx: &LateContext, ex: &Expr, arms: &[Arm], expr: &Expr) {
+    // matches on `bool`s are linted by `MATCH_BOOL`
+    if arms.len() != 2 || arms[0].guard.is_some() || cx.tcx.expr_ty(ex).sty == ty::TyBool {
+        return;
+    }
+    if arms[1].pats.len() != 1 || arms[1].pats[0].node != PatKind::Wild {
+        return;
+    }
+
+    let lits = arms[0].pats.iter().map(|pat| {
+        if let PatKind::Lit(ref lit) = pat.node {
+            Some(lit)
+        } else {
+            None
+        }
+    }).collect::<Option<Vec<_>>>();
+    let lits = match lits {
+        Some(lits) => lits,
+        None => return,
+    };
+
+    let (op, join) = match (bool_lit(&arms[0].body), bool_lit(&arms[1].body)) {
+        (Some(true), Some(false)) => ("==", " || "),
+        (Some(false), Some(true)) => ("!=", " && "),
+        _ => return,
+    };
+
+    if lits.len() >= 3 && cx.current_level(MATCH_LITERALS_CONTAINS) != Allow &&
+       lits.iter().all(|lit| is_int_or_char(lit)) {
+        let lits = lits.iter().map(|lit| snippet(cx, lit.span, "..")).collect::<Vec<_>>().join(", ");
+        span_note_and_lint(cx,
+                           MATCH_LITERALS_CONTAINS,
+                           expr.span,
+                           "this match is a membership test on literals",
+                           expr.span,
+                           &format!("consider using `{}[{}].contains(&{})`",
+                                    if op == "==" { "" } else { "!" },
+                                    lits,
+                                    snippet(cx, ex.span, "x")));
+        return;
+    }
+
+    // the matched expression would be evaluated several times
+    let is_path = if let ExprPath(..) = ex.node { true } else { false };
+    let sugg = if lits.len() == 1 || is_path {
+        let ex = snippet(cx, ex.span, "x");
+        Some(lits.iter()
+                 .map(|lit| format!("{} {} {}", ex, op, snippet(cx, lit.span, "..")))
+                 .collect::<Vec<_>>()
+                 .join(join))
+    } else {
+        None
+    };
+
+    span_lint_and_then(cx,
+                       MATCH_AS_BOOL,
+                       expr.span,
+                       "this match only produces a boolean, consider using a comparison instead",
+                       move |db| {
+                           if let Some(sugg) = sugg {
+                               db.span_suggestion(expr.span, "try this", sugg);
+                           }
+                       });
+}
+
+fn is_int_or_char(lit: &Expr) -> bool {
+    match constant_simple(lit) {
+        Some(Constant::Int(..)) | Some(Constant::Char(_)) | Some(Constant::Byte(_)) => true,
+        _ => false,
+    }
+}
+
+/// Return the value of a (possibly block-wrapped) boolean literal.
+fn bool_lit(expr: &Expr) -> Option<bool> {
+    if let ExprLit(ref lit) = strip_block(expr).node {
+        if let LitKind::Bool(b) = lit.node {
+            return Some(b);
+        }
+    }
+    None
+}
+
 fn check_overlapping_arms(cx: &LateContext, ex: &Expr, arms: &[Arm]) {
     if arms.len() >= 2 && cx.tcx.expr_ty(ex).is_integral() {
         let ranges = all_ranges(cx, arms);
@@ -325,6 +551,65 @@ fn check_match_ref_pats(cx: &LateContext, ex: &Expr, arms: &[Arm], source: Match
     }
 }
 
+fn check_redundant_pattern_matching(cx: &LateContext, ex: &Expr, arms: &[Arm], expr: &Expr) {
+    if arms.is_empty() || arms[0].pats.len() != 1 {
+        return;
+    }
+
+    let name = match arms[0].pats[0].node {
+        PatKind::TupleStruct(ref path, Some(ref pats)) if pats.len() == 1 && pats[0].node == PatKind::Wild => {
+            path.segments.last().map(|seg| seg.identifier.name.as_str())
+        }
+        PatKind::Path(ref path) => path.segments.last().map(|seg| seg.identifier.name.as_str()),
+        PatKind::Ident(BindByValue(MutImmutable), ident, None) => Some(ident.node.name.as_str()),
+        _ => None,
+    };
+
+    let (ty_path, good_method) = match name.as_ref().map(|name| &**name) {
+        Some("Some") => (&OPTION_PATH, "is_some()"),
+        Some("None") => (&OPTION_PATH, "is_none()"),
+        Some("Ok") => (&RESULT_PATH, "is_ok()"),
+        Some("Err") => (&RESULT_PATH, "is_err()"),
+        _ => return,
+    };
+
+    if !match_type(cx, cx.tcx.expr_ty(ex), ty_path) {
+        return;
+    }
+
+    let span = Span {
+        lo: expr.span.lo,
+        hi: ex.span.hi,
+        expn_id: expr.span.expn_id,
+    };
+    span_lint_and_then(cx,
+                       REDUNDANT_PATTERN_MATCHING,
+                       arms[0].pats[0].span,
+                       &format!("redundant pattern matching, consider using `{}`", good_method),
+                       |db| {
+                           db.span_suggestion(span,
+                                              "try this",
+                                              format!("if {}.{}", snippet(cx, ex.span, "_"), good_method));
+                       });
+}
+
+fn check_if_let_ignored_err(cx: &LateContext, ex: &Expr, arms: &[Arm], expr: &Expr) {
+    if_let_chain! {[
+        arms[0].pats.len() == 1,
+        let PatKind::TupleStruct(ref path, _) = arms[0].pats[0].node,
+        path.segments.last().map_or(false, |segment| segment.identifier.name.as_str() == "Ok"),
+        match_type(cx, cx.tcx.expr_ty(ex), &RESULT_PATH)
+    ], {
+        span_note_and_lint(cx,
+                           IF_LET_IGNORED_ERR,
+                           expr.span,
+                           "the `Err` case of this `if let Ok(..)` is silently ignored",
+                           ex.span,
+                           "consider handling the error in an `else` block, or explaining there why it can be \
+                            ignored");
+    }}
+}
+
 /// Get all arms that are unbounded PatRange-s.
 fn all_ranges(cx: &LateContext, arms: &[Arm]) -> Vec<SpannedRange<ConstVal>> {
     arms.iter()
@@ -409,6 +694,102 @@ fn type_ranges(ranges: &[SpannedRange<ConstVal>]) -> TypedRanges {
     }
 }
 
+fn check_if_as_match_guard(cx: &LateContext, arms: &[Arm]) {
+    if_let_chain! {[
+        arms.len() == 2,
+        arms[0].pats.len() == 1,
+        arms[0].guard.is_none(),
+        arms[1].pats.len() == 1,
+        arms[1].pats[0].node == PatKind::Wild,
+        arms[1].guard.is_none(),
+        let ExprIf(ref cond, ref then, Some(ref els)) = strip_block(&arms[0].body).node,
+        SpanlessEq::new(cx).eq_expr(strip_block(els), strip_block(&arms[1].body))
+    ], {
+        let mut visitor = BindingUseVisitor {
+            bindings: HashSet::new(),
+            used: false,
+        };
+        visitor.visit_pat(&arms[0].pats[0]);
+        visitor.visit_expr(cond);
+        if !visitor.used {
+            return;
+        }
+
+        // moving a value out in a guarded arm is an error (E0008)
+        let parent = cx.tcx.map.get_parent(arms[0].body.id);
+        let mut visitor = MoveBindingVisitor {
+            cx: cx,
+            env: ty::ParameterEnvironment::for_item(cx.tcx, parent),
+            moves: false,
+        };
+        visitor.visit_pat(&arms[0].pats[0]);
+        if visitor.moves {
+            return;
+        }
+
+        span_help_and_lint(cx,
+                           IF_AS_MATCH_GUARD,
+                           arms[0].body.span,
+                           "this `if` on the bound value could be a match guard",
+                           &format!("consider using a guard: `{} if {} => {}`",
+                                    snippet(cx, arms[0].pats[0].span, ".."),
+                                    snippet(cx, cond.span, ".."),
+                                    snippet(cx, then.span, "..")));
+    }}
+}
+
+/// Return the inner expression of blocks which only contain an expression.
+fn strip_block(expr: &Expr) -> &Expr {
+    if let ExprBlock(ref block) = expr.node {
+        if let (true, &Some(ref inner)) = (block.stmts.is_empty(), &block.expr) {
+            return strip_block(inner);
+        }
+    }
+    expr
+}
+
+/// Collect the bindings of a pattern and then check whether an expression uses any of them.
+struct BindingUseVisitor {
+    bindings: HashSet<Name>,
+    used: bool,
+}
+
+impl<'v> Visitor<'v> for BindingUseVisitor {
+    fn visit_pat(&mut self, pat: &'v Pat) {
+        if let PatKind::Ident(_, ref ident, _) = pat.node {
+            self.bindings.insert(ident.node.name);
+        }
+        walk_pat(self, pat);
+    }
+
+    fn visit_expr(&mut self, expr: &'v Expr) {
+        if let ExprPath(None, ref path) = expr.node {
+            if path.segments.len() == 1 && self.bindings.contains(&path.segments[0].identifier.name) {
+                self.used = true;
+            }
+        }
+        walk_expr(self, expr);
+    }
+}
+
+/// Check whether a pattern binds a value of a non-`Copy` type by move.
+struct MoveBindingVisitor<'a, 'tcx: 'a> {
+    cx: &'a LateContext<'a, 'tcx>,
+    env: ty::ParameterEnvironment<'a, 'tcx>,
+    moves: bool,
+}
+
+impl<'a, 'tcx: 'a, 'v> Visitor<'v> for MoveBindingVisitor<'a, 'tcx> {
+    fn visit_pat(&mut self, pat: &'v Pat) {
+        if let PatKind::Ident(BindByValue(_), _, _) = pat.node {
+            if self.cx.tcx.pat_ty(pat).moves_by_default(&self.env, pat.span) {
+                self.moves = true;
+            }
+        }
+        walk_pat(self, pat);
+    }
+}
+
 fn is_unit_expr(expr: &Expr) -> bool {
     match expr.node {
         ExprTup(ref v) if v.is_empty() => true,
@@ -1,19 +1,23 @@
+use consts::{constant, Constant, Sign};
 use rustc::lint::*;
 use rustc::middle::const_eval::EvalHint::ExprTypeChecked;
 use rustc::middle::const_eval::{ConstVal, eval_const_expr_partial};
 use rustc::middle::cstore::CrateStore;
+use rustc::middle::def::Def;
 use rustc::middle::subst::{Subst, TypeSpace};
 use rustc::middle::ty;
 use rustc_front::hir::*;
 use std::borrow::Cow;
 use std::{fmt, iter};
+use syntax::ast::{Attribute, FloatTy, IntTy, UintTy};
 use syntax::codemap::Span;
 use syntax::ptr::P;
-use utils::{get_trait_def_id, implements_trait, in_external_macro, in_macro, match_path, match_trait_method,
-            match_type, method_chain_args, snippet, snippet_opt, span_lint, span_lint_and_then, span_note_and_lint,
-            walk_ptrs_ty, walk_ptrs_ty_depth};
-use utils::{BTREEMAP_ENTRY_PATH, DEFAULT_TRAIT_PATH, HASHMAP_ENTRY_PATH, OPTION_PATH, RESULT_PATH, STRING_PATH,
-            VEC_PATH};
+use utils::{get_enclosing_fn_ret_ty, get_parent_expr, get_single_expr, get_trait_def_id, implements_trait,
+            in_external_macro, in_macro, is_integer_literal, match_def_path, match_path, match_trait_method, match_type,
+            method_chain_args, snippet, snippet_opt, span_help_and_lint, span_lint, span_lint_and_then, span_note_and_lint,
+            walk_ptrs_ty, walk_ptrs_ty_depth, LimitStack};
+use utils::{BTREEMAP_ENTRY_PATH, BTREEMAP_PATH, COMMAND_PATH, DEFAULT_TRAIT_PATH, DOUBLE_ENDED_ITERATOR_PATH,
+            FROM_TRAIT_PATH, HASHMAP_ENTRY_PATH, HASHMAP_PATH, OPTION_PATH, RESULT_PATH, STRING_PATH, VEC_PATH};
 use utils::MethodArgs;
 
 #[derive(Clone)]
@@ -70,6 +74,18 @@ declare_lint! {
     "calling `String::to_string` which is inefficient"
 }
 
+/// **What it does:** This lint checks for `.to_owned()` method calls on values of type `String`.
+///
+/// **Why is this bad?** The value is already owned, `.to_owned()` just clones it. `.clone()` says so.
+///
+/// **Known problems:** None
+///
+/// **Example:** `s.to_owned()` where `s: String`
+declare_lint! {
+    pub STRING_TO_OWNED, Allow,
+    "calling `String::to_owned`, which is a `clone()` in disguise"
+}
+
 /// **What it does:** This lint checks for methods that should live in a trait implementation of a `std` trait (see [llogiq's blog post](http://llogiq.github.io/2015/07/30/traits.html) for further information) instead of an inherent implementation.
 ///
 /// **Why is this bad?** Implementing the traits improve ergonomics for users of the code, often with very little cost. Also people seeing a `mul(..)` method may expect `*` to work equally, so you should have good reason to disappoint them.
@@ -310,6 +326,372 @@ declare_lint! {
      `_.split(\"x\")`"
 }
 
+/// **What it does:** This lint checks for calls to `.nth(0)` on an iterator.
+///
+/// **Why is this bad?** `.next()` does the same thing and is more readable.
+///
+/// **Known problems:** None.
+///
+/// **Example:** `s.chars().nth(0)` could be `s.chars().next()`
+declare_lint! {
+    pub ITER_NTH_ZERO,
+    Warn,
+    "using `.nth(0)` on an iterator, which is more succinctly expressed as `.next()`"
+}
+
+/// **What it does:** This lint checks for `.collect::<Vec<_>>()` immediately followed by `.len()`
+/// or `.is_empty()`.
+///
+/// **Why is this bad?** The intermediate `Vec` is allocated only to be thrown away, `.count()` or
+/// `.next().is_none()` give the same answer without allocating.
+///
+/// **Known problems:** None.
+///
+/// **Example:** `iter.collect::<Vec<_>>().len()` could be `iter.count()`, and
+/// `iter.map(f).collect::<Vec<_>>().is_empty()` could be `iter.map(f).next().is_none()`
+declare_lint! {
+    pub NEEDLESS_COLLECT,
+    Warn,
+    "collecting an iterator into a `Vec` only to call `len()` or `is_empty()` on it"
+}
+
+/// **What it does:** This lint checks for `.as_bytes().to_vec()` on a temporary `String`.
+///
+/// **Why is this bad?** `.into_bytes()` reuses the buffer of the `String` instead of copying it.
+///
+/// **Known problems:** Only temporaries are linted, as a `String` stored in a variable might still
+/// be used afterwards.
+///
+/// **Example:** `format!("{}", x).as_bytes().to_vec()` could be `format!("{}", x).into_bytes()`
+declare_lint! {
+    pub STRING_AS_BYTES_TO_VEC,
+    Warn,
+    "using `.as_bytes().to_vec()` on a temporary `String`, which is better expressed as `.into_bytes()`"
+}
+
+/// **What it does:** This lint checks for `.rev().next()` on a `DoubleEndedIterator`.
+///
+/// **Why is this bad?** `.next_back()` does the same thing without the reversing adapter.
+///
+/// **Known problems:** Unlike `.rev()`, `.next_back()` does not consume the iterator, so the
+/// receiver needs to be mutable if it is not a temporary.
+///
+/// **Example:** `v.iter().rev().next()` could be `v.iter().next_back()`
+declare_lint! {
+    pub REVERSED_NEXT,
+    Warn,
+    "using `.rev().next()` on an iterator, which is more succinctly expressed as `.next_back()`"
+}
+
+/// **What it does:** This lint checks for `.count()` on an iterator being compared to `0`.
+///
+/// **Why is this bad?** `.count()` walks the whole iterator only to check whether it yields any
+/// element, `.next().is_none()` stops at the first one.
+///
+/// **Known problems:** Unlike `.count()`, `.next()` does not consume the iterator, so the
+/// receiver needs to be mutable if it is not a temporary.
+///
+/// **Example:** `iter.count() == 0` could be `iter.next().is_none()`
+declare_lint! {
+    pub ITER_COUNT_ZERO,
+    Warn,
+    "comparing `.count()` of an iterator to zero, which is better expressed with `.next()`"
+}
+
+/// **What it does:** This lint checks for `.unwrap()` on the result of the `checked_*`
+/// arithmetic methods of integers, e.g. `x.checked_add(y).unwrap()`.
+///
+/// **Why is this bad?** The panic message says nothing about the overflow. Either overflow is not
+/// expected and the plain operator (which panics in debug builds) will do, or `.expect(..)` should
+/// document why it cannot happen.
+///
+/// **Known problems:** The plain operator does not panic on overflow in release builds.
+///
+/// **Example:** `x.checked_add(1).unwrap()`
+declare_lint! {
+    pub CHECKED_UNWRAP,
+    Allow,
+    "using `.unwrap()` on the result of a `checked_*` arithmetic method"
+}
+
+/// **What it does:** This lint checks for `.remove(0)` on a `Vec`.
+///
+/// **Why is this bad?** Removing the first element shifts all the remaining ones, which is O(n).
+/// When elements are removed from the front repeatedly, a `VecDeque` and its `pop_front()` method
+/// are a better fit.
+///
+/// **Known problems:** A single `.remove(0)` on a short `Vec` is fine.
+///
+/// **Example:** `while !v.is_empty() { let x = v.remove(0); .. }`
+declare_lint! {
+    pub VEC_REMOVE_ZERO,
+    Warn,
+    "using `.remove(0)` on a `Vec`, which shifts all its elements"
+}
+
+/// **What it does:** This lint checks for `.unwrap_or_else(..)` on an `Option` or `Result` with a closure which
+/// only returns a literal or a `Copy` variable.
+///
+/// **Why is this bad?** Computing such a value costs nothing, so there is no point in doing it lazily.
+/// `.unwrap_or(..)` is shorter and clearer.
+///
+/// **Known problems:** None.
+///
+/// **Example:** `x.unwrap_or_else(|| 0)`
+declare_lint! {
+    pub UNNECESSARY_LAZY_EVALUATION,
+    Warn,
+    "using `.unwrap_or_else(|| x)` where `x` is trivial; suggests using `.unwrap_or(x)` instead"
+}
+
+/// **What it does:** This lint checks for `.iter().find(..)` or `.iter().filter(..).next()` on a `HashMap` or
+/// `BTreeMap` which compares the keys to a value.
+///
+/// **Why is this bad?** This goes through all the entries of the map, `.get(&key)` finds the value directly.
+///
+/// **Known problems:** `.get(..)` only returns the value, not the key-value pair.
+///
+/// **Example:** `map.iter().find(|&(k, _)| *k == key)`
+declare_lint! {
+    pub LINEAR_KEY_SEARCH,
+    Warn,
+    "searching a key in a map by iterating over it; suggests using `.get(..)` instead"
+}
+
+/// **What it does:** This lint checks for `.clone().into_iter().next()` on a `Vec`.
+///
+/// **Why is this bad?** The whole `Vec` is cloned only to get its first element, `.first().cloned()` only clones that
+/// element.
+///
+/// **Known problems:** None.
+///
+/// **Example:** `v.clone().into_iter().next()`
+declare_lint! {
+    pub CLONE_INTO_ITER_NEXT,
+    Warn,
+    "using `.clone().into_iter().next()` on a `Vec`, which clones all its elements to get the first one"
+}
+
+/// **What it does:** This lint checks for `.filter_map(|x| if cond { Some(x) } else { None })` on an Iterator.
+///
+/// **Why is this bad?** The closure does not transform the element, it only decides whether to keep it. This is
+/// exactly what `.filter(..)` does, and is more readable.
+///
+/// **Known problems:** `filter` passes a reference to its closure, so the condition may need to be adapted for
+/// types which are not `Copy`.
+///
+/// **Example:** `iter.filter_map(|x| if x > 2 { Some(x) } else { None })`
+declare_lint! {
+    pub FILTER_MAP_IF,
+    Warn,
+    "using `.filter_map(|x| if cond { Some(x) } else { None })`, which is more succinctly expressed as \
+     `.filter(..)`"
+}
+
+/// **What it does:** This lint checks for `.map(|x| x)` anywhere in an iterator chain.
+///
+/// **Why is this bad?** The closure returns its argument unchanged, so the adapter does nothing but make the chain
+/// longer to read.
+///
+/// **Known problems:** None.
+///
+/// **Example:** `v.iter().map(|x| x).filter(|x| **x > 2)`
+declare_lint! {
+    pub IDENTITY_MAP,
+    Warn,
+    "using `.map(|x| x)` on an iterator, which does nothing"
+}
+
+/// **What it does:** This lint checks for `.into_boxed_slice().into_vec()` on a `Vec` and
+/// `.into_boxed_str().into_string()` on a `String`.
+///
+/// **Why is this bad?** The value is converted to a box and straight back, which gives the original value.
+///
+/// **Known problems:** The conversion to a box drops any excess capacity, use `shrink_to_fit()` if this is intended.
+///
+/// **Example:** `v.into_boxed_slice().into_vec()`
+declare_lint! {
+    pub BOXED_ROUND_TRIP,
+    Warn,
+    "using `.into_boxed_slice().into_vec()` or `.into_boxed_str().into_string()`, which gives back the original value"
+}
+
+/// **What it does:** This lint checks for `.fold(0, |acc, x| acc + x)` and `.fold(1, |acc, x| acc * x)` on an
+/// Iterator.
+///
+/// **Why is this bad?** This is exactly what `.sum()` and `.product()` compute, and they say so.
+///
+/// **Known problems:** `sum` and `product` are still unstable (they need `#![feature(iter_arith)]`), hence this
+/// lint is `Allow` by default. The type of the result may need to be annotated.
+///
+/// **Example:** `v.iter().fold(0, |acc, x| acc + x)`
+declare_lint! {
+    pub FOLD_TO_SUM,
+    Allow,
+    "using `.fold(0, |a, b| a + b)` or `.fold(1, |a, b| a * b)`; suggests using `.sum()` or `.product()`"
+}
+
+/// **What it does:** This lint checks for `.filter(|x| x.is_some()).map(|x| x.unwrap())` on an Iterator of
+/// `Option`s.
+///
+/// **Why is this bad?** `.filter_map(|x| x)` does the same in one step, without an `unwrap` which cannot fail.
+///
+/// **Known problems:** None.
+///
+/// **Example:** `iter.filter(|x| x.is_some()).map(|x| x.unwrap())`
+declare_lint! {
+    pub FILTER_MAP_UNWRAP,
+    Warn,
+    "using `.filter(|x| x.is_some()).map(|x| x.unwrap())`, which is more succinctly expressed as \
+     `.filter_map(|x| x)`"
+}
+
+/// **What it does:** This lint checks for `.map(|b| b as char).collect::<String>()` on an Iterator of bytes.
+///
+/// **Why is this bad?** Each byte is turned into the `char` with the same code point, which is only right for ASCII.
+/// If the bytes are UTF-8, non-ASCII characters are garbled, `String::from_utf8(..)` decodes them properly.
+///
+/// **Known problems:** This is right if the bytes are really Latin-1.
+///
+/// **Example:** `bytes.iter().map(|&b| b as char).collect::<String>()`
+declare_lint! {
+    pub BYTES_AS_CHARS,
+    Warn,
+    "collecting bytes casted to `char`s into a `String`, which does not decode UTF-8"
+}
+
+/// **What it does:** This lint checks for `.as_ref().map(|s| s.as_str())`, `.as_ref().map(String::as_str)` or
+/// `.as_ref().map(|s| &**s)` on an `Option`.
+///
+/// **Why is this bad?** The closure only derefs its argument, `.as_ref().map(Deref::deref)` says so and works for
+/// any type implementing `Deref`.
+///
+/// **Known problems:** `Deref` needs to be imported.
+///
+/// **Example:** `opt.as_ref().map(|s| s.as_str())`
+declare_lint! {
+    pub OPTION_AS_REF_DEREF,
+    Allow,
+    "using `.as_ref().map(|s| s.as_str())` on an `Option`; suggests using `.as_ref().map(Deref::deref)` instead"
+}
+
+/// **What it does:** This lint checks for `checked_*`, `saturating_*` and `wrapping_*` arithmetic methods of integers
+/// called with their identity element, e.g. `x.wrapping_add(0)` or `x.saturating_mul(1)`.
+///
+/// **Why is this bad?** The call cannot overflow and returns `x` (or `Some(x)`), which is likely a mistake or a
+/// leftover.
+///
+/// **Known problems:** None.
+///
+/// **Example:** `x.saturating_add(0)`
+declare_lint! {
+    pub NOOP_ARITHMETIC_METHOD,
+    Warn,
+    "using an overflow-aware arithmetic method with its identity element, like `x.wrapping_add(0)`"
+}
+
+/// **What it does:** This lint checks for `.iter().cloned().collect()` on a slice (or something dereferencing to a
+/// slice) which collects into a `Vec`.
+///
+/// **Why is this bad?** `.to_vec()` (or `.clone()` if the source already is a `Vec`) says the same thing, is shorter
+/// and may be faster.
+///
+/// **Known problems:** None.
+///
+/// **Example:** `let v: Vec<u32> = s.iter().cloned().collect();`
+declare_lint! {
+    pub ITER_CLONED_COLLECT,
+    Warn,
+    "using `.iter().cloned().collect()` on a slice to create a `Vec`; suggests using `.to_vec()` or `.clone()` instead"
+}
+
+/// **What it does:** This lint checks for chained owning conversions of slices, like
+/// `s.to_owned().to_vec()` or `s.to_vec().to_owned()`.
+///
+/// **Why is this bad?** The first call already creates a `Vec`, the second one only clones it again.
+///
+/// **Known problems:** None.
+///
+/// **Example:** `s.to_vec().to_owned()`
+declare_lint! {
+    pub DOUBLE_OWNING_CONVERSION,
+    Warn,
+    "using `.to_owned().to_vec()` or `.to_vec().to_owned()` on a slice; suggests using `.to_vec()` once"
+}
+
+/// **What it does:** This lint checks for `.chars().count()` on a string being compared to `0`.
+///
+/// **Why is this bad?** Decoding and counting all the characters is not needed to know whether
+/// the string is empty, `.is_empty()` is both faster and more readable.
+///
+/// **Known problems:** None.
+///
+/// **Example:** `s.chars().count() == 0` could be `s.is_empty()`
+declare_lint! {
+    pub CHARS_COUNT_EMPTY,
+    Warn,
+    "comparing `.chars().count()` to zero; suggests using `.is_empty()` instead"
+}
+
+/// **What it does:** This lint checks for `.bytes().count()` on strings.
+///
+/// **Why is this bad?** `.len()` gives the number of bytes directly instead of iterating over them.
+///
+/// **Known problems:** None.
+///
+/// **Example:** `s.bytes().count()` could be `s.len()`
+declare_lint! {
+    pub BYTES_COUNT_TO_LEN,
+    Warn,
+    "using `.bytes().count()` on a string; suggests using `.len()` instead"
+}
+
+/// **What it does:** This lint checks for `.drain(..).collect()` on a `Vec` which collects into
+/// a `Vec`.
+///
+/// **Why is this bad?** All the elements are moved one by one into a new allocation, while
+/// `std::mem::replace(&mut v, Vec::new())` only moves the `Vec` itself, and if the drained `Vec`
+/// is not used anymore it can simply be moved.
+///
+/// **Known problems:** None.
+///
+/// **Example:** `let w: Vec<_> = v.drain(..).collect();`
+declare_lint! {
+    pub DRAIN_COLLECT,
+    Warn,
+    "using `.drain(..).collect()` to take all the elements of a `Vec`; suggests using `std::mem::replace` instead"
+}
+
+/// **What it does:** This lint checks for `.map(|x| x as f64).sum()` where the integers are too
+/// wide to all be represented exactly by the float type, e.g. `u64` to `f64` or `u32` to `f32`.
+///
+/// **Why is this bad?** Each cast may round the value, and the rounding errors accumulate in the
+/// sum.
+///
+/// **Known problems:** Summing the integers first and casting the result may overflow.
+///
+/// **Example:** `v.iter().map(|&x| x as f64).sum::<f64>()` where `v: Vec<u64>`
+declare_lint! {
+    pub INT_TO_FLOAT_SUM,
+    Allow,
+    "summing integers cast to a float type which cannot represent them all exactly"
+}
+
+/// **What it does:** This lint checks for `.iter().nth(n).unwrap()` on a `Vec`, a slice or an
+/// array.
+///
+/// **Why is this bad?** Indexing says the same thing, is shorter and does not go through an
+/// iterator.
+///
+/// **Known problems:** None.
+///
+/// **Example:** `v.iter().nth(2).unwrap()` could be `&v[2]`
+declare_lint! {
+    pub ITER_NTH_UNWRAP,
+    Warn,
+    "using `.iter().nth(n).unwrap()` on a `Vec`, slice or array; suggests indexing instead"
+}
+
 impl LintPass for MethodsPass {
     fn get_lints(&self) -> LintArray {
         lint_array!(EXTEND_FROM_SLICE,
@@ -317,6 +699,7 @@ impl LintPass for MethodsPass {
                     RESULT_UNWRAP_USED,
                     STR_TO_STRING,
                     STRING_TO_STRING,
+                    STRING_TO_OWNED,
                     SHOULD_IMPLEMENT_TRAIT,
                     WRONG_SELF_CONVENTION,
                     WRONG_PUB_SELF_CONVENTION,
@@ -328,7 +711,32 @@ impl LintPass for MethodsPass {
                     CLONE_ON_COPY,
                     CLONE_DOUBLE_REF,
                     NEW_RET_NO_SELF,
-                    SINGLE_CHAR_PATTERN)
+                    SINGLE_CHAR_PATTERN,
+                    ITER_NTH_ZERO,
+                    NEEDLESS_COLLECT,
+                    STRING_AS_BYTES_TO_VEC,
+                    REVERSED_NEXT,
+                    ITER_COUNT_ZERO,
+                    CHECKED_UNWRAP,
+                    VEC_REMOVE_ZERO,
+                    CLONE_INTO_ITER_NEXT,
+                    FILTER_MAP_IF,
+                    LINEAR_KEY_SEARCH,
+                    UNNECESSARY_LAZY_EVALUATION,
+                    IDENTITY_MAP,
+                    BOXED_ROUND_TRIP,
+                    FOLD_TO_SUM,
+                    FILTER_MAP_UNWRAP,
+                    BYTES_AS_CHARS,
+                    OPTION_AS_REF_DEREF,
+                    NOOP_ARITHMETIC_METHOD,
+                    ITER_CLONED_COLLECT,
+                    DOUBLE_OWNING_CONVERSION,
+                    CHARS_COUNT_EMPTY,
+                    BYTES_COUNT_TO_LEN,
+                    DRAIN_COLLECT,
+                    INT_TO_FLOAT_SUM,
+                    ITER_NTH_UNWRAP)
     }
 }
 
@@ -343,16 +751,32 @@ impl LateLintPass for MethodsPass {
                 // Chain calls
                 if let Some(arglists) = method_chain_args(expr, &["unwrap"]) {
                     lint_unwrap(cx, expr, arglists[0]);
+                    lint_checked_unwrap(cx, expr, arglists[0]);
+                    if let Some(arglists) = method_chain_args(expr, &["iter", "nth", "unwrap"]) {
+                        lint_iter_nth_unwrap(cx, expr, arglists[1]);
+                    }
                 } else if let Some(arglists) = method_chain_args(expr, &["to_string"]) {
                     lint_to_string(cx, expr, arglists[0]);
+                } else if let Some(arglists) = method_chain_args(expr, &["to_owned", "to_vec"]) {
+                    lint_double_owning_conversion(cx, expr, arglists[0], arglists[1]);
+                } else if let Some(arglists) = method_chain_args(expr, &["to_vec", "to_owned"]) {
+                    lint_double_owning_conversion(cx, expr, arglists[0], arglists[1]);
+                } else if let Some(arglists) = method_chain_args(expr, &["to_owned"]) {
+                    lint_string_to_owned(cx, expr, arglists[0]);
                 } else if let Some(arglists) = method_chain_args(expr, &["ok", "expect"]) {
                     lint_ok_expect(cx, expr, arglists[0]);
                 } else if let Some(arglists) = method_chain_args(expr, &["map", "unwrap_or"]) {
                     lint_map_unwrap_or(cx, expr, arglists[0], arglists[1]);
                 } else if let Some(arglists) = method_chain_args(expr, &["map", "unwrap_or_else"]) {
                     lint_map_unwrap_or_else(cx, expr, arglists[0], arglists[1]);
+                } else if let Some(arglists) = method_chain_args(expr, &["iter", "filter", "next"]) {
+                    if !lint_linear_key_search(cx, expr, arglists[0], arglists[1]) {
+                        lint_filter_next(cx, expr, arglists[1]);
+                    }
                 } else if let Some(arglists) = method_chain_args(expr, &["filter", "next"]) {
                     lint_filter_next(cx, expr, arglists[0]);
+                } else if let Some(arglists) = method_chain_args(expr, &["iter", "find"]) {
+                    lint_linear_key_search(cx, expr, arglists[0], arglists[1]);
                 } else if let Some(arglists) = method_chain_args(expr, &["find", "is_some"]) {
                     lint_search_is_some(cx, expr, "find", arglists[0], arglists[1]);
                 } else if let Some(arglists) = method_chain_args(expr, &["position", "is_some"]) {
@@ -361,8 +785,51 @@ impl LateLintPass for MethodsPass {
                     lint_search_is_some(cx, expr, "rposition", arglists[0], arglists[1]);
                 } else if let Some(arglists) = method_chain_args(expr, &["extend"]) {
                     lint_extend(cx, expr, arglists[0]);
+                } else if let Some(arglists) = method_chain_args(expr, &["nth"]) {
+                    lint_iter_nth_zero(cx, expr, arglists[0]);
+                } else if let Some(arglists) = method_chain_args(expr, &["collect", "len"]) {
+                    lint_needless_collect(cx, expr, arglists[0], arglists[1], "count()");
+                } else if let Some(arglists) = method_chain_args(expr, &["collect", "is_empty"]) {
+                    lint_needless_collect(cx, expr, arglists[0], arglists[1], "next().is_none()");
+                } else if let Some(arglists) = method_chain_args(expr, &["as_bytes", "to_vec"]) {
+                    lint_string_as_bytes_to_vec(cx, expr, arglists[0]);
+                } else if let Some(arglists) = method_chain_args(expr, &["rev", "next"]) {
+                    lint_reversed_next(cx, expr, arglists[0]);
+                } else if let Some(arglists) = method_chain_args(expr, &["remove"]) {
+                    lint_vec_remove_zero(cx, expr, arglists[0]);
+                } else if let Some(arglists) = method_chain_args(expr, &["clone", "into_iter", "next"]) {
+                    lint_clone_into_iter_next(cx, expr, arglists[0]);
+                } else if let Some(arglists) = method_chain_args(expr, &["filter_map"]) {
+                    lint_filter_map_if(cx, expr, arglists[0]);
+                } else if let Some(arglists) = method_chain_args(expr, &["map", "collect"]) {
+                    lint_bytes_as_chars(cx, expr, arglists[0]);
+                } else if let Some(arglists) = method_chain_args(expr, &["iter", "cloned", "collect"]) {
+                    lint_iter_cloned_collect(cx, expr, arglists[0], arglists[1]);
+                } else if let Some(arglists) = method_chain_args(expr, &["filter", "map"]) {
+                    if !lint_filter_map_unwrap(cx, expr, arglists[0], arglists[1]) {
+                        lint_identity_map(cx, expr, arglists[1]);
+                    }
+                } else if let Some(arglists) = method_chain_args(expr, &["as_ref", "map"]) {
+                    lint_option_as_ref_deref(cx, expr, arglists[0], arglists[1]);
+                } else if let Some(arglists) = method_chain_args(expr, &["map"]) {
+                    lint_identity_map(cx, expr, arglists[0]);
+                } else if let Some(arglists) = method_chain_args(expr, &["into_boxed_slice", "into_vec"]) {
+                    lint_boxed_round_trip(cx, expr, arglists[0], &VEC_PATH, "into_boxed_slice().into_vec()");
+                } else if let Some(arglists) = method_chain_args(expr, &["into_boxed_str", "into_string"]) {
+                    lint_boxed_round_trip(cx, expr, arglists[0], &STRING_PATH, "into_boxed_str().into_string()");
+                } else if let Some(arglists) = method_chain_args(expr, &["fold"]) {
+                    lint_fold_to_sum(cx, expr, arglists[0]);
+                } else if let Some(arglists) = method_chain_args(expr, &["bytes", "count"]) {
+                    lint_bytes_count_to_len(cx, expr, arglists[0]);
+                } else if let Some(arglists) = method_chain_args(expr, &["drain", "collect"]) {
+                    lint_drain_collect(cx, expr, arglists[0]);
+                } else if let Some(arglists) = method_chain_args(expr, &["map", "sum"]) {
+                    lint_int_to_float_sum(cx, expr, arglists[0]);
                 }
                 lint_or_fun_call(cx, expr, &name.node.as_str(), &args);
+                if name.node.as_str() == "unwrap_or_else" {
+                    lint_unnecessary_lazy_evaluation(cx, expr, args);
+                }
                 if args.len() == 1 && name.node.as_str() == "clone" {
                     lint_clone_on_copy(cx, expr);
                     lint_clone_double_ref(cx, expr, &args[0]);
@@ -372,11 +839,17 @@ impl LateLintPass for MethodsPass {
                         lint_single_char_pattern(cx, expr, &args[pos]);
                     }
                 }
+                for &(method, identity) in &NOOP_ARITHMETIC_METHODS {
+                    if name.node.as_str() == method && args.len() == 2 {
+                        lint_noop_arithmetic_method(cx, expr, method, args, identity);
+                    }
+                }
             }
-            ExprBinary(op, ref lhs, ref rhs) if op.node == BiEq || op.node == BiNe => {
-                if !lint_chars_next(cx, expr, lhs, rhs, op.node == BiEq) {
+            ExprBinary(op, ref lhs, ref rhs) => {
+                if (op.node == BiEq || op.node == BiNe) && !lint_chars_next(cx, expr, lhs, rhs, op.node == BiEq) {
                     lint_chars_next(cx, expr, rhs, lhs, op.node == BiEq);
                 }
+                lint_iter_count_zero(cx, expr, op.node, lhs, rhs);
             }
             _ => (),
         }
@@ -458,6 +931,102 @@ impl LateLintPass for MethodsPass {
     }
 }
 
+/// **What it does:** This lint checks for several consecutive `.arg(..)` calls on a
+/// `std::process::Command`. The number of calls allowed can be configured with the
+/// `#[chained_command_args = "N"]` attribute, the default is 2.
+///
+/// **Why is this bad?** A single `.args(&[..])` call is shorter and easier to read.
+///
+/// **Known problems:** None.
+///
+/// **Example:** `Command::new("ls").arg("-l").arg("-a").arg("-h")` could be `Command::new("ls").args(&["-l", "-a", "-h"])`
+declare_lint! {
+    pub CHAINED_COMMAND_ARGS,
+    Warn,
+    "several consecutive `.arg(..)` calls on a `Command`; suggests using `.args(&[..])` instead"
+}
+
+pub struct ChainedCommandArgs {
+    limit: LimitStack,
+}
+
+impl ChainedCommandArgs {
+    pub fn new(limit: u64) -> Self {
+        ChainedCommandArgs { limit: LimitStack::new(limit) }
+    }
+}
+
+impl LintPass for ChainedCommandArgs {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(CHAINED_COMMAND_ARGS)
+    }
+}
+
+impl LateLintPass for ChainedCommandArgs {
+    fn check_expr(&mut self, cx: &LateContext, expr: &Expr) {
+        if !is_command_arg(cx, expr) || in_macro(cx, expr.span) {
+            return;
+        }
+        // only lint the outermost call of the chain
+        if let Some(parent) = get_parent_expr(cx, expr) {
+            if let ExprMethodCall(_, _, ref args) = parent.node {
+                if args[0].id == expr.id && is_command_arg(cx, parent) {
+                    return;
+                }
+            }
+        }
+
+        let mut values = vec![];
+        let mut base = expr;
+        while let ExprMethodCall(_, _, ref args) = base.node {
+            if !is_command_arg(cx, base) {
+                break;
+            }
+            values.push(&*args[1]);
+            base = &*args[0];
+        }
+        values.reverse();
+
+        if values.len() as u64 > self.limit.limit() {
+            span_lint_and_then(cx,
+                               CHAINED_COMMAND_ARGS,
+                               expr.span,
+                               &format!("{} consecutive calls to `.arg(..)`", values.len()),
+                               |db| {
+                                   // the array needs all its elements to have the same type
+                                   let ty = cx.tcx.expr_ty(values[0]);
+                                   if values.iter().all(|value| cx.tcx.expr_ty(value) == ty) {
+                                       let values = values.iter()
+                                                          .map(|value| snippet(cx, value.span, ".."))
+                                                          .collect::<Vec<_>>();
+                                       db.span_suggestion(expr.span,
+                                                          "use `.args(&[..])` instead",
+                                                          format!("{}.args(&[{}])",
+                                                                  snippet(cx, base.span, "_"),
+                                                                  values.join(", ")));
+                                   }
+                               });
+        }
+    }
+
+    fn enter_lint_attrs(&mut self, cx: &LateContext, attrs: &[Attribute]) {
+        self.limit.push_attrs(cx.sess(), attrs, "chained_command_args");
+    }
+    fn exit_lint_attrs(&mut self, cx: &LateContext, attrs: &[Attribute]) {
+        self.limit.pop_attrs(cx.sess(), attrs, "chained_command_args");
+    }
+}
+
+/// Is this expression a call to `Command::arg`?
+fn is_command_arg(cx: &LateContext, expr: &Expr) -> bool {
+    if let ExprMethodCall(ref name, _, ref args) = expr.node {
+        name.node.as_str() == "arg" && args.len() == 2 &&
+        match_type(cx, walk_ptrs_ty(cx.tcx.expr_ty(&args[0])), &COMMAND_PATH)
+    } else {
+        false
+    }
+}
+
 /// Checks for the `OR_FUN_CALL` lint.
 fn lint_or_fun_call(cx: &LateContext, expr: &Expr, name: &str, args: &[P<Expr>]) {
     /// Check for `unwrap_or(T::new())` or `unwrap_or(T::default())`.
@@ -539,8 +1108,30 @@ fn lint_or_fun_call(cx: &LateContext, expr: &Expr, name: &str, args: &[P<Expr>])
                              format!("{}.{}_{}({})", snippet(cx, self_expr.span, "_"), name, suffix, sugg));
     }
 
+    /// Does this expression call a function which is not an enum variant or struct constructor?
+    fn has_non_ctor_call(cx: &LateContext, expr: &Expr) -> bool {
+        match expr.node {
+            ExprCall(ref fun, ref args) => !is_ctor(cx, fun) || args.iter().any(|arg| has_non_ctor_call(cx, arg)),
+            ExprMethodCall(..) => true,
+            _ => false,
+        }
+    }
+
+    /// Is this path a constructor such as `Some` or `Ok`?
+    fn is_ctor(cx: &LateContext, fun: &Expr) -> bool {
+        match cx.tcx.def_map.borrow().get(&fun.id).map(|d| d.full_def()) {
+            Some(Def::Variant(..)) | Some(Def::Struct(..)) => true,
+            _ => false,
+        }
+    }
+
     if args.len() == 2 {
         if let ExprCall(ref fun, ref or_args) = args[1].node {
+            // `or(Some(x))` is fine as long as `x` is cheap, but not `or(Some(compute()))`
+            if is_ctor(cx, fun) && !or_args.iter().any(|arg| has_non_ctor_call(cx, arg)) {
+                return;
+            }
+
             let or_has_args = !or_args.is_empty();
             if !check_unwrap_or_default(cx, name, fun, &args[0], &args[1], or_has_args, expr.span) {
                 check_general_case(cx, name, fun, &args[0], &args[1], or_has_args, expr.span);
@@ -643,18 +1234,87 @@ fn lint_unwrap(cx: &LateContext, expr: &Expr, unwrap_args: &MethodArgs) {
     };
 
     if let Some((lint, kind, none_value)) = mess {
-        span_lint(cx,
-                  lint,
-                  expr.span,
-                  &format!("used unwrap() on {} value. If you don't want to handle the {} case gracefully, consider \
-                            using expect() to provide a better panic
-                            message",
-                           kind,
-                           none_value));
+        let mut db = span_lint(cx,
+                               lint,
+                               expr.span,
+                               &format!("used unwrap() on {} value. If you don't want to handle the {} case \
+                                         gracefully, consider using expect() to provide a better panic \
+                                         message",
+                                        kind,
+                                        none_value));
+        if error_converts_to_fn_error(cx, expr, cx.tcx.expr_ty(&unwrap_args[0])) {
+            db.span_note(expr.span,
+                         "the enclosing function returns a compatible `Result`, the error can be passed on with \
+                          `try!(..)`");
+        }
     }
 }
 
-#[allow(ptr_arg)]
+/// Can the error of the `Result` type `ty` be converted by `try!` into the error returned by the function `expr`
+/// is in?
+fn error_converts_to_fn_error<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, expr: &Expr, ty: ty::Ty<'tcx>) -> bool {
+    if_let_chain! {[
+        let Some(err_ty) = get_error_type(cx, ty),
+        let Some(ret_ty) = get_enclosing_fn_ret_ty(cx, expr.id),
+        let Some(ret_err_ty) = get_error_type(cx, ret_ty),
+        let Some(from_trait) = get_trait_def_id(cx, &FROM_TRAIT_PATH)
+    ], {
+        return implements_trait(cx, ret_err_ty, from_trait, Some(vec![err_ty]));
+    }}
+    false
+}
+
+#[allow(ptr_arg)]
+// Type of MethodArgs is potentially a Vec
+/// lint use of `unwrap()` on `checked_add()` and friends
+fn lint_checked_unwrap(cx: &LateContext, expr: &Expr, unwrap_args: &MethodArgs) {
+    if_let_chain! {[
+        let ExprMethodCall(ref name, _, ref checked_args) = unwrap_args[0].node,
+        checked_args.len() == 2,
+        let Some(&(_, op)) = CHECKED_METHODS.iter().find(|&&(method, _)| name.node.as_str() == method),
+        cx.tcx.expr_ty(&checked_args[0]).is_integral()
+    ], {
+        span_help_and_lint(cx,
+                           CHECKED_UNWRAP,
+                           expr.span,
+                           &format!("called `.unwrap()` on the result of `.{}()`, which panics without \
+                                     explaining the overflow",
+                                    name.node),
+                           &format!("if overflow is not expected, consider using `{} {} {}`, or document why it \
+                                     cannot happen with `.expect(..)`",
+                                    snippet(cx, checked_args[0].span, "_"),
+                                    op,
+                                    snippet(cx, checked_args[1].span, "_")));
+    }}
+}
+
+#[allow(ptr_arg)]
+// Type of MethodArgs is potentially a Vec
+/// lint use of `checked_*`, `saturating_*` and `wrapping_*` methods with their identity element
+fn lint_noop_arithmetic_method(cx: &LateContext, expr: &Expr, method: &str, args: &MethodArgs, identity: u64) {
+    if_let_chain! {[
+        cx.tcx.expr_ty(&args[0]).is_integral(),
+        let Some((Constant::Int(value, _, Sign::Plus), _)) = constant(cx, &args[1]),
+        value == identity
+    ], {
+        let value = snippet(cx, args[0].span, "_");
+        // `checked_*` methods return an `Option`
+        let sugg = if method.starts_with("checked_") {
+            format!("Some({})", value)
+        } else {
+            value.into_owned()
+        };
+        span_lint_and_then(cx,
+                           NOOP_ARITHMETIC_METHOD,
+                           expr.span,
+                           &format!("calling `.{}({})` does nothing", method, identity),
+                           |db| {
+                               db.span_suggestion(expr.span, "try", sugg);
+                           });
+    }}
+}
+
+#[allow(ptr_arg)]
 // Type of MethodArgs is potentially a Vec
 /// lint use of `to_string()` for `&str`s and `String`s
 fn lint_to_string(cx: &LateContext, expr: &Expr, to_string_args: &MethodArgs) {
@@ -674,6 +1334,129 @@ fn lint_to_string(cx: &LateContext, expr: &Expr, to_string_args: &MethodArgs) {
     }
 }
 
+#[allow(ptr_arg)]
+// Type of MethodArgs is potentially a Vec
+/// lint use of `to_owned()` for `String`s
+fn lint_string_to_owned(cx: &LateContext, expr: &Expr, to_owned_args: &MethodArgs) {
+    if match_type(cx, walk_ptrs_ty(cx.tcx.expr_ty(&to_owned_args[0])), &STRING_PATH) &&
+       match_type(cx, cx.tcx.expr_ty(expr), &STRING_PATH) {
+        span_lint_and_then(cx,
+                           STRING_TO_OWNED,
+                           expr.span,
+                           "`String::to_owned` only clones the `String`",
+                           |db| {
+                               db.span_suggestion(expr.span,
+                                                  "use `clone()` instead",
+                                                  format!("{}.clone()", snippet(cx, to_owned_args[0].span, "_")));
+                           });
+    }
+}
+
+#[allow(ptr_arg)]
+// Type of MethodArgs is potentially a Vec
+/// lint use of `to_owned().to_vec()` and `to_vec().to_owned()` on slices
+fn lint_double_owning_conversion(cx: &LateContext, expr: &Expr, first_args: &MethodArgs, second_args: &MethodArgs) {
+    // `second_args[0]` is the result of the first conversion
+    if match_type(cx, cx.tcx.expr_ty(&second_args[0]), &VEC_PATH) &&
+       match_type(cx, cx.tcx.expr_ty(expr), &VEC_PATH) {
+        span_lint_and_then(cx,
+                           DOUBLE_OWNING_CONVERSION,
+                           expr.span,
+                           "this creates a `Vec` and then clones it",
+                           |db| {
+                               db.span_suggestion(expr.span,
+                                                  "use `to_vec()` once",
+                                                  format!("{}.to_vec()", snippet(cx, first_args[0].span, "_")));
+                           });
+    }
+}
+
+#[allow(ptr_arg)]
+// Type of MethodArgs is potentially a Vec
+/// lint use of `bytes().count()` for `str`s and `String`s
+fn lint_bytes_count_to_len(cx: &LateContext, expr: &Expr, bytes_args: &MethodArgs) {
+    let obj_ty = walk_ptrs_ty(cx.tcx.expr_ty(&bytes_args[0]));
+    if obj_ty.sty == ty::TyStr || match_type(cx, obj_ty, &STRING_PATH) {
+        span_lint_and_then(cx,
+                           BYTES_COUNT_TO_LEN,
+                           expr.span,
+                           "using `bytes().count()` to get the length of a string",
+                           |db| {
+                               db.span_suggestion(expr.span,
+                                                  "use `len()` instead",
+                                                  format!("{}.len()", snippet(cx, bytes_args[0].span, "_")));
+                           });
+    }
+}
+
+#[allow(ptr_arg)]
+// Type of MethodArgs is potentially a Vec
+/// lint use of `drain(..).collect()` for `Vec`s
+fn lint_drain_collect(cx: &LateContext, expr: &Expr, drain_args: &MethodArgs) {
+    let obj_ty = cx.tcx.expr_ty(&drain_args[0]);
+    if drain_args.len() == 2 && match_type(cx, walk_ptrs_ty(obj_ty), &VEC_PATH) &&
+       match_type(cx, cx.tcx.expr_ty(&drain_args[1]), &["core", "ops", "RangeFull"]) &&
+       match_type(cx, cx.tcx.expr_ty(expr), &VEC_PATH) {
+        let vec = snippet(cx, drain_args[0].span, "_");
+        // `v` may already be a `&mut Vec<_>`
+        let vec = if let ty::TyRef(..) = obj_ty.sty {
+            vec
+        } else {
+            Cow::Owned(format!("&mut {}", vec))
+        };
+        span_lint_and_then(cx,
+                           DRAIN_COLLECT,
+                           expr.span,
+                           "you are moving all the elements of a `Vec` into a new one",
+                           |db| {
+                               db.span_suggestion(expr.span,
+                                                  "use `std::mem::replace` instead",
+                                                  format!("std::mem::replace({}, Vec::new())", vec));
+                               db.span_note(expr.span, "if the `Vec` is not used anymore, it can simply be moved");
+                           });
+    }
+}
+
+#[allow(ptr_arg)]
+// Type of MethodArgs is potentially a Vec
+/// lint use of `map(|x| x as f64).sum()` for wide integers
+fn lint_int_to_float_sum(cx: &LateContext, expr: &Expr, map_args: &MethodArgs) {
+    if_let_chain! {[
+        map_args.len() == 2,
+        match_trait_method(cx, expr, &["core", "iter", "Iterator"]),
+        let ExprClosure(_, _, ref body) = map_args[1].node,
+        body.stmts.is_empty(),
+        let Some(ref ret) = body.expr,
+        let ExprCast(ref int, _) = ret.node,
+        let ty::TyFloat(float_ty) = cx.tcx.expr_ty(ret).sty
+    ], {
+        // the width of the mantissa, plus the implicit bit
+        let exact_nbits = if float_ty == FloatTy::F32 {
+            24
+        } else {
+            53
+        };
+        let int_nbits = match cx.tcx.expr_ty(int).sty {
+            ty::TyInt(IntTy::I8) | ty::TyUint(UintTy::U8) => 8,
+            ty::TyInt(IntTy::I16) | ty::TyUint(UintTy::U16) => 16,
+            ty::TyInt(IntTy::I32) | ty::TyUint(UintTy::U32) => 32,
+            ty::TyInt(_) | ty::TyUint(_) => 64,
+            _ => return,
+        };
+        if int_nbits > exact_nbits {
+            span_note_and_lint(cx,
+                               INT_TO_FLOAT_SUM,
+                               expr.span,
+                               &format!("the integers are rounded when cast to `{}` and the errors accumulate in the \
+                                         sum",
+                                        float_ty),
+                               ret.span,
+                               "if the sum of the integers cannot overflow, consider summing them first and casting \
+                                the result");
+        }
+    }}
+}
+
 #[allow(ptr_arg)]
 // Type of MethodArgs is potentially a Vec
 /// lint use of `ok().expect()` for `Result`s
@@ -777,6 +1560,65 @@ fn lint_filter_next(cx: &LateContext, expr: &Expr, filter_args: &MethodArgs) {
     }
 }
 
+#[allow(ptr_arg)]
+// Type of MethodArgs is potentially a Vec
+/// lint `map.iter().find(|&(k, _)| *k == key)`, returns whether it linted
+fn lint_linear_key_search(cx: &LateContext, expr: &Expr, iter_args: &MethodArgs, search_args: &MethodArgs) -> bool {
+    /// Is `expr` `k`, `*k` or `**k`?
+    fn is_key(expr: &Expr, key: Ident) -> bool {
+        match expr.node {
+            ExprUnary(UnDeref, ref inner) => is_key(inner, key),
+            ExprPath(None, ref path) => path.segments.len() == 1 && path.segments[0].identifier.name == key.name,
+            _ => false,
+        }
+    }
+
+    let map_ty = walk_ptrs_ty(cx.tcx.expr_ty(&iter_args[0]));
+    if !match_type(cx, map_ty, &HASHMAP_PATH) && !match_type(cx, map_ty, &BTREEMAP_PATH) {
+        return false;
+    }
+
+    if_let_chain! {[
+        search_args.len() == 2,
+        let ExprClosure(_, ref decl, ref body) = search_args[1].node,
+        decl.inputs.len() == 1,
+        let PatKind::Ref(ref tuple, _) = decl.inputs[0].pat.node,
+        let PatKind::Tup(ref fields) = tuple.node,
+        fields.len() == 2,
+        let PatKind::Ident(_, ref key, None) = fields[0].node,
+        body.stmts.is_empty(),
+        let Some(ref cmp) = body.expr,
+        let ExprBinary(ref op, ref left, ref right) = cmp.node,
+        op.node == BiEq
+    ], {
+        let value = if is_key(left, key.node) && !is_key(right, key.node) {
+            right
+        } else if is_key(right, key.node) && !is_key(left, key.node) {
+            left
+        } else {
+            return false;
+        };
+
+        let value = match value.node {
+            ExprAddrOf(_, _) => snippet(cx, value.span, "_").into_owned(),
+            _ => format!("&{}", snippet(cx, value.span, "_")),
+        };
+        let map = snippet(cx, iter_args[0].span, "_");
+        // no `span_suggestion`: `get` gives `Option<&V>`, not the `Option<(&K, &V)>` of the search
+        span_help_and_lint(cx,
+                           LINEAR_KEY_SEARCH,
+                           expr.span,
+                           "searching a key by iterating over the whole map",
+                           &format!("consider looking the key up directly with `{}.get({})` or `{}.contains_key({})`",
+                                    map,
+                                    value,
+                                    map,
+                                    value));
+        return true;
+    }}
+    false
+}
+
 #[allow(ptr_arg)]
 // Type of MethodArgs is potentially a Vec
 /// lint searching an Iterator followed by `is_some()`
@@ -802,6 +1644,607 @@ fn lint_search_is_some(cx: &LateContext, expr: &Expr, search_method: &str, searc
     }
 }
 
+#[allow(ptr_arg)]
+// Type of MethodArgs is potentially a Vec
+/// lint use of `nth(0)` for Iterators
+fn lint_iter_nth_zero(cx: &LateContext, expr: &Expr, nth_args: &MethodArgs) {
+    if_let_chain! {[
+        match_trait_method(cx, expr, &["core", "iter", "Iterator"]),
+        let Some((Constant::Int(0, _, _), _)) = constant(cx, &nth_args[1])
+    ], {
+        span_lint_and_then(cx,
+                           ITER_NTH_ZERO,
+                           expr.span,
+                           "called `.nth(0)` on an Iterator. This is more succinctly expressed by calling \
+                            `.next()` instead",
+                           |db| {
+                               db.span_suggestion(expr.span,
+                                                  "try this",
+                                                  format!("{}.next()", snippet(cx, nth_args[0].span, "_")));
+                           });
+    }}
+}
+
+#[allow(ptr_arg)]
+// Type of MethodArgs is potentially a Vec
+/// lint use of `iter().nth(n).unwrap()` for `Vec`s, slices and arrays
+fn lint_iter_nth_unwrap(cx: &LateContext, expr: &Expr, nth_args: &MethodArgs) {
+    // `nth_args[0]` is the `iter()` call
+    if let Some((span, _)) = derefs_to_slice(cx, &nth_args[0], &cx.tcx.expr_ty(&nth_args[0])) {
+        span_lint_and_then(cx,
+                           ITER_NTH_UNWRAP,
+                           expr.span,
+                           "called `.iter().nth(..).unwrap()` on a slice-like container. Indexing it is more readable",
+                           |db| {
+                               db.span_suggestion(expr.span,
+                                                  "try this",
+                                                  format!("&{}[{}]",
+                                                          snippet(cx, span, "_"),
+                                                          snippet(cx, nth_args[1].span, "_")));
+                           });
+    }
+}
+
+#[allow(ptr_arg)]
+// Type of MethodArgs is potentially a Vec
+/// lint use of `collect::<Vec<_>>()` followed by `len()` or `is_empty()`
+fn lint_needless_collect(cx: &LateContext, expr: &Expr, collect_args: &MethodArgs, len_args: &MethodArgs,
+                         replacement: &str) {
+    // `len_args[0]` is the `collect()` call itself
+    if match_trait_method(cx, &len_args[0], &["core", "iter", "Iterator"]) &&
+       match_type(cx, cx.tcx.expr_ty(&len_args[0]), &VEC_PATH) {
+        span_lint_and_then(cx,
+                           NEEDLESS_COLLECT,
+                           expr.span,
+                           "avoid allocating a `Vec` just to inspect its length",
+                           |db| {
+                               db.span_suggestion(expr.span,
+                                                  "try this",
+                                                  format!("{}.{}",
+                                                          snippet(cx, collect_args[0].span, "_"),
+                                                          replacement));
+                           });
+    }
+}
+
+#[allow(ptr_arg)]
+// Type of MethodArgs is potentially a Vec
+/// lint use of `iter().cloned().collect()` on slices
+fn lint_iter_cloned_collect(cx: &LateContext, expr: &Expr, iter_args: &MethodArgs, cloned_args: &MethodArgs) {
+    // `cloned_args[0]` is the `iter()` call
+    if_let_chain! {[
+        match_type(cx, cx.tcx.expr_ty(expr), &VEC_PATH),
+        let Some((span, _)) = derefs_to_slice(cx, &cloned_args[0], &cx.tcx.expr_ty(&cloned_args[0]))
+    ], {
+        // `v.clone()` only gives a `Vec` back if `v` is a `Vec` or a reference to one
+        let source_ty = match cx.tcx.expr_ty(&iter_args[0]).sty {
+            ty::TyRef(_, ty::TypeAndMut { ty, .. }) => ty,
+            _ => cx.tcx.expr_ty(&iter_args[0]),
+        };
+        let (msg, method) = if match_type(cx, source_ty, &VEC_PATH) {
+            ("called `iter().cloned().collect()` on a `Vec` to create a `Vec`. Calling `clone()` is both faster and more \
+              readable",
+             "clone")
+        } else {
+            ("called `iter().cloned().collect()` on a slice to create a `Vec`. Calling `to_vec()` is both faster and \
+              more readable",
+             "to_vec")
+        };
+        span_lint_and_then(cx,
+                           ITER_CLONED_COLLECT,
+                           expr.span,
+                           msg,
+                           |db| {
+                               db.span_suggestion(expr.span,
+                                                  "try this",
+                                                  format!("{}.{}()", snippet(cx, span, "_"), method));
+                           });
+    }}
+}
+
+#[allow(ptr_arg)]
+// Type of MethodArgs is potentially a Vec
+/// lint use of `as_bytes().to_vec()` for temporary `String`s
+fn lint_string_as_bytes_to_vec(cx: &LateContext, expr: &Expr, as_bytes_args: &MethodArgs) {
+    let string = &as_bytes_args[0];
+    let is_temporary = match string.node {
+        ExprCall(..) | ExprMethodCall(..) => true,
+        _ => false,
+    };
+    if is_temporary && match_type(cx, cx.tcx.expr_ty(string), &STRING_PATH) {
+        span_lint_and_then(cx,
+                           STRING_AS_BYTES_TO_VEC,
+                           expr.span,
+                           "calling `as_bytes().to_vec()` on a `String` copies its buffer",
+                           |db| {
+                               db.span_suggestion(expr.span,
+                                                  "use `into_bytes()` to reuse it instead",
+                                                  format!("{}.into_bytes()", snippet(cx, string.span, "_")));
+                           });
+    }
+}
+
+#[allow(ptr_arg)]
+// Type of MethodArgs is potentially a Vec
+/// lint use of `into_boxed_slice().into_vec()` for `Vec`s and `into_boxed_str().into_string()` for `String`s
+fn lint_boxed_round_trip(cx: &LateContext, expr: &Expr, into_box_args: &MethodArgs, path: &[&str], calls: &str) {
+    if match_type(cx, cx.tcx.expr_ty(&into_box_args[0]), path) {
+        span_lint_and_then(cx,
+                           BOXED_ROUND_TRIP,
+                           expr.span,
+                           &format!("calling `{}` gives back the original value", calls),
+                           |db| {
+                               db.span_suggestion(expr.span,
+                                                  "remove the conversions",
+                                                  snippet(cx, into_box_args[0].span, "_").into_owned());
+                           });
+    }
+}
+
+#[allow(ptr_arg)]
+// Type of MethodArgs is potentially a Vec
+/// lint use of `fold(0, |acc, x| acc + x)` and `fold(1, |acc, x| acc * x)` for `Iterator`s
+fn lint_fold_to_sum(cx: &LateContext, expr: &Expr, fold_args: &MethodArgs) {
+    fn is_binding(expr: &Expr, ident: Ident) -> bool {
+        if let ExprPath(None, ref path) = expr.node {
+            !path.global && path.segments.len() == 1 && path.segments[0].identifier.name == ident.name
+        } else {
+            false
+        }
+    }
+
+    if_let_chain! {[
+        fold_args.len() == 3,
+        match_trait_method(cx, expr, &["core", "iter", "Iterator"]),
+        let ExprClosure(_, ref decl, ref body) = fold_args[2].node,
+        decl.inputs.len() == 2,
+        let PatKind::Ident(BindByValue(_), acc, None) = decl.inputs[0].pat.node,
+        let PatKind::Ident(BindByValue(_), elem, None) = decl.inputs[1].pat.node,
+        body.stmts.is_empty(),
+        let Some(ref ret) = body.expr,
+        let ExprBinary(ref op, ref left, ref right) = ret.node,
+        // both operators are commutative
+        (is_binding(left, acc.node) && is_binding(right, elem.node)) ||
+        (is_binding(left, elem.node) && is_binding(right, acc.node))
+    ], {
+        let method = match op.node {
+            BiAdd if is_integer_literal(&fold_args[1], 0) => "sum",
+            BiMul if is_integer_literal(&fold_args[1], 1) => "product",
+            _ => return,
+        };
+        span_lint_and_then(cx,
+                           FOLD_TO_SUM,
+                           expr.span,
+                           &format!("this `.fold(..)` computes the {} of the elements", method),
+                           |db| {
+                               db.span_suggestion(expr.span,
+                                                  &format!("use `.{}()` instead", method),
+                                                  format!("{}.{}()", snippet(cx, fold_args[0].span, "_"), method));
+                           });
+    }}
+}
+
+#[allow(ptr_arg)]
+// Type of MethodArgs is potentially a Vec
+/// lint use of `rev().next()` for `DoubleEndedIterator`s
+fn lint_reversed_next(cx: &LateContext, expr: &Expr, rev_args: &MethodArgs) {
+    // `.rev()` already requires `DoubleEndedIterator`, but make sure this is really
+    // `Iterator::rev` and not some unrelated method
+    if_let_chain! {[
+        match_trait_method(cx, expr, &["core", "iter", "Iterator"]),
+        let ExprMethodCall(_, _, ref next_args) = expr.node,
+        match_trait_method(cx, &next_args[0], &["core", "iter", "Iterator"]),
+        let Some(double_ended) = get_trait_def_id(cx, &DOUBLE_ENDED_ITERATOR_PATH),
+        implements_trait(cx, cx.tcx.expr_ty(&rev_args[0]), double_ended, None)
+    ], {
+        span_lint_and_then(cx,
+                           REVERSED_NEXT,
+                           expr.span,
+                           "called `.rev().next()` on a `DoubleEndedIterator`. This is more succinctly expressed \
+                            by calling `.next_back()` instead",
+                           |db| {
+                               db.span_suggestion(expr.span,
+                                                  "try this",
+                                                  format!("{}.next_back()", snippet(cx, rev_args[0].span, "_")));
+                           });
+    }}
+}
+
+#[allow(ptr_arg)]
+// Type of MethodArgs is potentially a Vec
+/// lint use of `remove(0)` for `Vec`s
+fn lint_vec_remove_zero(cx: &LateContext, expr: &Expr, remove_args: &MethodArgs) {
+    if_let_chain! {[
+        remove_args.len() == 2,
+        match_type(cx, walk_ptrs_ty(cx.tcx.expr_ty(&remove_args[0])), &VEC_PATH),
+        let Some((Constant::Int(0, _, _), _)) = constant(cx, &remove_args[1])
+    ], {
+        span_note_and_lint(cx,
+                           VEC_REMOVE_ZERO,
+                           expr.span,
+                           "calling `.remove(0)` on a `Vec` shifts all its remaining elements",
+                           expr.span,
+                           "consider using a `VecDeque` and its `pop_front()` method if elements are removed \
+                            from the front repeatedly");
+    }}
+}
+
+#[allow(ptr_arg)]
+// Type of MethodArgs is potentially a Vec
+/// lint use of `unwrap_or_else(|| x)` with a trivial `x`
+fn lint_unnecessary_lazy_evaluation(cx: &LateContext, expr: &Expr, unwrap_args: &MethodArgs) {
+    let self_ty = cx.tcx.expr_ty(&unwrap_args[0]);
+    if !match_type(cx, self_ty, &OPTION_PATH) && !match_type(cx, self_ty, &RESULT_PATH) {
+        return;
+    }
+
+    if_let_chain! {[
+        unwrap_args.len() == 2,
+        let ExprClosure(_, ref decl, ref body) = unwrap_args[1].node,
+        // `Result::unwrap_or_else` passes the error, which must be ignored
+        decl.inputs.iter().all(|arg| arg.pat.node == PatKind::Wild),
+        body.stmts.is_empty(),
+        let Some(ref value) = body.expr
+    ], {
+        let trivial = match value.node {
+            ExprLit(_) => true,
+            ExprPath(None, _) => {
+                let ty = cx.tcx.expr_ty(value);
+                let parent = cx.tcx.map.get_parent(expr.id);
+                let parameter_environment = ty::ParameterEnvironment::for_item(cx.tcx, parent);
+                !ty.moves_by_default(&parameter_environment, value.span)
+            }
+            _ => false,
+        };
+        if trivial {
+            span_lint_and_then(cx,
+                               UNNECESSARY_LAZY_EVALUATION,
+                               expr.span,
+                               "unnecessary closure used to compute a trivial value",
+                               |db| {
+                                   db.span_suggestion(expr.span,
+                                                      "use `unwrap_or(..)` instead",
+                                                      format!("{}.unwrap_or({})",
+                                                              snippet(cx, unwrap_args[0].span, "_"),
+                                                              snippet(cx, value.span, "_")));
+                               });
+        }
+    }}
+}
+
+#[allow(ptr_arg)]
+// Type of MethodArgs is potentially a Vec
+/// lint use of `clone().into_iter().next()` for `Vec`s
+fn lint_clone_into_iter_next(cx: &LateContext, expr: &Expr, clone_args: &MethodArgs) {
+    if match_type(cx, walk_ptrs_ty(cx.tcx.expr_ty(&clone_args[0])), &VEC_PATH) {
+        let vec = snippet(cx, clone_args[0].span, "_");
+        span_note_and_lint(cx,
+                           CLONE_INTO_ITER_NEXT,
+                           expr.span,
+                           "cloning a whole `Vec` just to get its first element",
+                           expr.span,
+                           &format!("consider using `{}.first().cloned()` instead", vec));
+    }
+}
+
+#[allow(ptr_arg)]
+// Type of MethodArgs is potentially a Vec
+/// lint use of `filter(|x| x.is_some()).map(|x| x.unwrap())` for `Iterator`s, returns whether it linted
+fn lint_filter_map_unwrap(cx: &LateContext, expr: &Expr, filter_args: &MethodArgs, map_args: &MethodArgs) -> bool {
+    /// Get the binding of a closure of the form `|x| x.method()`.
+    fn calls_on_arg<'a>(closure: &'a Expr, method: &str) -> Option<&'a Pat> {
+        if_let_chain! {[
+            let ExprClosure(_, ref decl, ref body) = closure.node,
+            decl.inputs.len() == 1,
+            let PatKind::Ident(_, ident, None) = decl.inputs[0].pat.node,
+            body.stmts.is_empty(),
+            let Some(ref ret) = body.expr,
+            let ExprMethodCall(ref name, _, ref args) = ret.node,
+            name.node.as_str() == method,
+            args.len() == 1,
+            let ExprPath(None, ref path) = args[0].node,
+            !path.global && path.segments.len() == 1,
+            path.segments[0].identifier.name == ident.node.name
+        ], {
+            return Some(&decl.inputs[0].pat);
+        }}
+        None
+    }
+
+    if_let_chain! {[
+        filter_args.len() == 2,
+        map_args.len() == 2,
+        match_trait_method(cx, expr, &["core", "iter", "Iterator"]),
+        calls_on_arg(&filter_args[1], "is_some").is_some(),
+        let Some(pat) = calls_on_arg(&map_args[1], "unwrap"),
+        match_type(cx, walk_ptrs_ty(cx.tcx.pat_ty(pat)), &OPTION_PATH)
+    ], {
+        // `unwrap` can only be called on a reference to an `Option` if it is `Copy`
+        let (_, ptr_depth) = walk_ptrs_ty_depth(cx.tcx.pat_ty(pat));
+        let derefs = iter::repeat('*').take(ptr_depth).collect::<String>();
+        span_lint_and_then(cx,
+                           FILTER_MAP_UNWRAP,
+                           expr.span,
+                           "called `filter(|x| x.is_some()).map(|x| x.unwrap())` on an Iterator",
+                           |db| {
+                               db.span_suggestion(expr.span,
+                                                  "use `filter_map(..)` instead",
+                                                  format!("{}.filter_map(|x| {}x)",
+                                                          snippet(cx, filter_args[0].span, "_"),
+                                                          derefs));
+                           });
+        return true;
+    }}
+    false
+}
+
+#[allow(ptr_arg)]
+// Type of MethodArgs is potentially a Vec
+/// lint use of `map(|b| b as char).collect::<String>()` for `Iterator`s of bytes
+fn lint_bytes_as_chars(cx: &LateContext, expr: &Expr, map_args: &MethodArgs) {
+    if_let_chain! {[
+        map_args.len() == 2,
+        match_type(cx, cx.tcx.expr_ty(expr), &STRING_PATH),
+        match_trait_method(cx, expr, &["core", "iter", "Iterator"]),
+        let ExprClosure(_, _, ref body) = map_args[1].node,
+        body.stmts.is_empty(),
+        let Some(ref ret) = body.expr,
+        let ExprCast(ref byte, _) = ret.node,
+        cx.tcx.expr_ty(ret).sty == ty::TyChar,
+        cx.tcx.expr_ty(byte).sty == ty::TyUint(UintTy::U8)
+    ], {
+        span_note_and_lint(cx,
+                           BYTES_AS_CHARS,
+                           expr.span,
+                           "collecting bytes casted to `char`s into a `String` does not decode UTF-8",
+                           expr.span,
+                           "non-ASCII bytes become unrelated characters, consider using `String::from_utf8(..)` if \
+                            the bytes are UTF-8");
+    }}
+}
+
+#[allow(ptr_arg)]
+// Type of MethodArgs is potentially a Vec
+/// lint use of `as_ref().map(|s| s.as_str())`, `as_ref().map(String::as_str)` and `as_ref().map(|s| &**s)` for
+/// `Option`s
+fn lint_option_as_ref_deref(cx: &LateContext, expr: &Expr, as_ref_args: &MethodArgs, map_args: &MethodArgs) {
+    fn is_deref_of(cx: &LateContext, expr: &Expr, ident: Ident) -> bool {
+        let inner = match expr.node {
+            ExprMethodCall(ref name, _, ref args) if args.len() == 1 => {
+                let self_ty = walk_ptrs_ty(cx.tcx.expr_ty(&args[0]));
+                let derefs = match &*name.node.as_str() {
+                    "as_str" => match_type(cx, self_ty, &STRING_PATH),
+                    "as_slice" => match_type(cx, self_ty, &VEC_PATH),
+                    _ => false,
+                };
+                if !derefs {
+                    return false;
+                }
+                &args[0]
+            }
+            ExprAddrOf(MutImmutable, ref deref) => {
+                match deref.node {
+                    ExprUnary(UnDeref, ref deref) => {
+                        match deref.node {
+                            ExprUnary(UnDeref, ref inner) => inner,
+                            _ => return false,
+                        }
+                    }
+                    _ => return false,
+                }
+            }
+            _ => return false,
+        };
+        if let ExprPath(None, ref path) = inner.node {
+            !path.global && path.segments.len() == 1 && path.segments[0].identifier.name == ident.name
+        } else {
+            false
+        }
+    }
+
+    /// Is this closure of the form `|s| s.as_str()` or `|s| &**s`?
+    fn closure_derefs(cx: &LateContext, decl: &FnDecl, body: &Block) -> bool {
+        if_let_chain! {[
+            decl.inputs.len() == 1,
+            let PatKind::Ident(BindByValue(MutImmutable), ident, None) = decl.inputs[0].pat.node,
+            body.stmts.is_empty(),
+            let Some(ref ret) = body.expr
+        ], {
+            return is_deref_of(cx, ret, ident.node);
+        }}
+        false
+    }
+
+    /// Is this a path to `String::as_str` or `Vec::as_slice`?
+    fn is_deref_method(cx: &LateContext, expr: &Expr, path: &Path) -> bool {
+        if_let_chain! {[
+            let Some(Def::Method(def_id)) = cx.tcx.def_map.borrow().get(&expr.id).map(|d| d.full_def()),
+            let Some(impl_id) = cx.tcx.impl_of_method(def_id),
+            let Some(segment) = path.segments.last()
+        ], {
+            let name = segment.identifier.name.as_str();
+            return (name == "as_str" && match_def_path(cx, impl_id, &STRING_PATH)) ||
+                   (name == "as_slice" && match_def_path(cx, impl_id, &["collections", "vec", "Vec<T>"]));
+        }}
+        false
+    }
+
+    if as_ref_args.len() != 1 || map_args.len() != 2 ||
+       !match_type(cx, walk_ptrs_ty(cx.tcx.expr_ty(&as_ref_args[0])), &OPTION_PATH) {
+        return;
+    }
+
+    let msg = match map_args[1].node {
+        ExprClosure(_, ref decl, ref body) if closure_derefs(cx, decl, body) => {
+            "called `as_ref().map(..)` on an `Option` with a closure which only derefs its argument".to_owned()
+        }
+        ExprPath(None, ref path) if is_deref_method(cx, &map_args[1], path) => {
+            format!("called `as_ref().map({})` on an `Option`, which only derefs its argument",
+                    snippet(cx, map_args[1].span, ".."))
+        }
+        _ => return,
+    };
+
+    span_note_and_lint(cx,
+                       OPTION_AS_REF_DEREF,
+                       expr.span,
+                       &msg,
+                       expr.span,
+                       &format!("consider using `{}.as_ref().map(Deref::deref)`",
+                                snippet(cx, as_ref_args[0].span, "_")));
+}
+
+#[allow(ptr_arg)]
+// Type of MethodArgs is potentially a Vec
+/// lint use of `map(|x| x)` for `Iterator`s
+fn lint_identity_map(cx: &LateContext, expr: &Expr, map_args: &MethodArgs) {
+    if_let_chain! {[
+        map_args.len() == 2,
+        match_trait_method(cx, expr, &["core", "iter", "Iterator"]),
+        let ExprClosure(_, ref decl, ref body) = map_args[1].node,
+        decl.inputs.len() == 1,
+        let PatKind::Ident(_, ident, None) = decl.inputs[0].pat.node,
+        body.stmts.is_empty(),
+        let Some(ref ret) = body.expr,
+        let ExprPath(None, ref path) = ret.node,
+        !path.global && path.segments.len() == 1,
+        path.segments[0].identifier.name == ident.node.name
+    ], {
+        span_lint_and_then(cx,
+                           IDENTITY_MAP,
+                           expr.span,
+                           "this `.map(..)` returns its argument unchanged",
+                           |db| {
+                               db.span_suggestion(expr.span,
+                                                  "remove it",
+                                                  snippet(cx, map_args[0].span, "_").into_owned());
+                           });
+    }}
+}
+
+#[allow(ptr_arg)]
+// Type of MethodArgs is potentially a Vec
+/// lint `filter_map` closures of the form `|x| if cond { Some(x) } else { None }`
+fn lint_filter_map_if(cx: &LateContext, expr: &Expr, filter_map_args: &MethodArgs) {
+    fn is_some_of(expr: &Expr, ident: Ident) -> bool {
+        if_let_chain! {[
+            let ExprCall(ref fun, ref args) = expr.node,
+            args.len() == 1,
+            let ExprPath(None, ref path) = fun.node,
+            match_path(path, &["Some"]),
+            let ExprPath(None, ref arg) = args[0].node,
+            !arg.global && arg.segments.len() == 1
+        ], {
+            return arg.segments[0].identifier.name == ident.name;
+        }}
+        false
+    }
+
+    fn is_none(expr: &Expr) -> bool {
+        if let ExprPath(None, ref path) = expr.node {
+            match_path(path, &["None"])
+        } else {
+            false
+        }
+    }
+
+    if_let_chain! {[
+        filter_map_args.len() == 2,
+        match_trait_method(cx, expr, &["core", "iter", "Iterator"]),
+        let ExprClosure(_, ref decl, ref body) = filter_map_args[1].node,
+        decl.inputs.len() == 1,
+        let PatKind::Ident(BindByValue(MutImmutable), ident, None) = decl.inputs[0].pat.node,
+        body.stmts.is_empty(),
+        let Some(ref if_expr) = body.expr,
+        let ExprIf(ref cond, ref then, Some(ref els)) = if_expr.node,
+        then.stmts.is_empty(),
+        let Some(ref then) = then.expr,
+        is_some_of(then, ident.node),
+        let Some(els) = get_single_expr(els),
+        is_none(els)
+    ], {
+        let ty = cx.tcx.pat_ty(&decl.inputs[0].pat);
+        let parent = cx.tcx.map.get_parent(expr.id);
+        let parameter_environment = ty::ParameterEnvironment::for_item(cx.tcx, parent);
+
+        span_lint_and_then(cx,
+                           FILTER_MAP_IF,
+                           expr.span,
+                           "this `.filter_map(..)` only keeps the elements for which a condition holds",
+                           |db| {
+                               if ty.moves_by_default(&parameter_environment, expr.span) {
+                                   db.span_help(expr.span,
+                                                "consider using `.filter(..)` instead, its closure gets a \
+                                                 reference to the element");
+                               } else {
+                                   db.span_suggestion(expr.span,
+                                                      "try",
+                                                      format!("{}.filter(|&{}| {})",
+                                                              snippet(cx, filter_map_args[0].span, "_"),
+                                                              ident.node.name,
+                                                              snippet(cx, cond.span, "..")));
+                               }
+                           });
+    }}
+}
+
+/// lint comparisons of `count()` to `0` for Iterators
+fn lint_iter_count_zero(cx: &LateContext, expr: &Expr, op: BinOp_, lhs: &Expr, rhs: &Expr) {
+    let (count, is_empty) = if is_integer_literal(rhs, 0) {
+        match op {
+            BiEq => (lhs, true),
+            BiNe | BiGt => (lhs, false),
+            _ => return,
+        }
+    } else if is_integer_literal(lhs, 0) {
+        match op {
+            BiEq => (rhs, true),
+            BiNe | BiLt => (rhs, false),
+            _ => return,
+        }
+    } else {
+        return;
+    };
+
+    if_let_chain! {[
+        let Some(arglists) = method_chain_args(count, &["chars", "count"]),
+        match_type(cx, walk_ptrs_ty(cx.tcx.expr_ty(&arglists[0][0])), &STRING_PATH) ||
+        walk_ptrs_ty(cx.tcx.expr_ty(&arglists[0][0])).sty == ty::TyStr
+    ], {
+        // only emptiness can be tested without counting, the byte length is not the number of `char`s
+        span_lint_and_then(cx,
+                           CHARS_COUNT_EMPTY,
+                           expr.span,
+                           "counting the `char`s of a string to check whether it is empty",
+                           |db| {
+                               db.span_suggestion(expr.span,
+                                                  "use `.is_empty()` instead",
+                                                  format!("{}{}.is_empty()",
+                                                          if is_empty { "" } else { "!" },
+                                                          snippet(cx, arglists[0][0].span, "_")));
+                           });
+        return;
+    }}
+
+    if_let_chain! {[
+        let Some(count_args) = method_chain_args(count, &["count"]),
+        match_trait_method(cx, count, &["core", "iter", "Iterator"])
+    ], {
+        let method = if is_empty { "is_none" } else { "is_some" };
+        span_lint_and_then(cx,
+                           ITER_COUNT_ZERO,
+                           expr.span,
+                           "comparing the `.count()` of an Iterator to zero walks through all its elements",
+                           |db| {
+                               db.span_suggestion(expr.span,
+                                                  "use `.next()` instead",
+                                                  format!("{}.next().{}()",
+                                                          snippet(cx, count_args[0][0].span, "_"),
+                                                          method));
+                           });
+    }}
+}
+
 /// Checks for the `CHARS_NEXT_CMP` lint.
 fn lint_chars_next(cx: &LateContext, expr: &Expr, chain: &Expr, other: &Expr, eq: bool) -> bool {
     if_let_chain! {[
@@ -944,6 +2387,29 @@ const PATTERN_METHODS: [(&'static str, usize); 17] = [
     ("trim_right_matches", 1),
 ];
 
+#[cfg_attr(rustfmt, rustfmt_skip)]
+const CHECKED_METHODS: [(&'static str, &'static str); 5] = [
+    ("checked_add", "+"),
+    ("checked_sub", "-"),
+    ("checked_mul", "*"),
+    ("checked_div", "/"),
+    ("checked_rem", "%"),
+];
+
+/// The overflow-aware arithmetic methods and the value for which they do nothing.
+const NOOP_ARITHMETIC_METHODS: [(&'static str, u64); 10] = [
+    ("checked_add", 0),
+    ("checked_sub", 0),
+    ("checked_mul", 1),
+    ("checked_div", 1),
+    ("saturating_add", 0),
+    ("saturating_sub", 0),
+    ("saturating_mul", 1),
+    ("wrapping_add", 0),
+    ("wrapping_sub", 0),
+    ("wrapping_mul", 1),
+];
+
 
 #[derive(Clone, Copy)]
 enum SelfKind {
@@ -1,16 +1,23 @@
+use consts::{constant, Constant, Sign};
 use reexport::*;
 use rustc::lint::*;
 use rustc::middle::const_eval::ConstVal::Float;
 use rustc::middle::const_eval::EvalHint::ExprTypeChecked;
 use rustc::middle::const_eval::eval_const_expr_partial;
 use rustc::middle::ty;
+use rustc::session::config::CrateTypeExecutable;
 use rustc_front::hir::*;
 use rustc_front::intravisit::FnKind;
 use rustc_front::util::{is_comparison_binop, binop_to_string};
+use syntax::ast::LitKind;
+use syntax::attr;
 use syntax::codemap::{Span, Spanned, ExpnFormat};
 use syntax::ptr::P;
-use utils::{get_item_name, match_path, snippet, get_parent_expr, span_lint};
+use utils::{get_item_name, match_path, snippet, get_parent_expr, span_lint, in_macro};
 use utils::{span_lint_and_then, walk_ptrs_ty, is_integer_literal, implements_trait};
+use utils::{is_expn_of, match_trait_method, match_type, span_note_and_lint};
+use utils::{match_def_path, span_help_and_lint};
+use utils::{BEGIN_UNWIND, BEGIN_UNWIND_FMT, OPTION_PATH, ORDERING_PATH, RESULT_PATH, STRING_PATH, VEC_PATH};
 
 /// **What it does:** This lint checks for function arguments and let bindings denoted as `ref`.
 ///
@@ -415,6 +422,514 @@ impl LateLintPass for UsedUnderscoreBinding {
     }
 }
 
+/// **What it does:** This lint checks for comparisons of an `Option` to `None`.
+///
+/// **Why is this bad?** `is_none()` and `is_some()` say the same thing more directly and do not
+/// need the inner type to implement `PartialEq`.
+///
+/// **Known problems:** None
+///
+/// **Example:** `if x == None { .. }` could be `if x.is_none() { .. }`
+declare_lint!(pub CMP_OPTION_NONE, Warn,
+              "comparing an `Option` to `None` with `==` or `!=`, suggests `is_none()` or `is_some()` instead");
+
+#[derive(Copy,Clone)]
+pub struct CmpOptionNone;
+
+impl LintPass for CmpOptionNone {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(CMP_OPTION_NONE)
+    }
+}
+
+impl LateLintPass for CmpOptionNone {
+    fn check_expr(&mut self, cx: &LateContext, expr: &Expr) {
+        if let ExprBinary(ref op, ref left, ref right) = expr.node {
+            let method = match op.node {
+                BiEq => "is_none()",
+                BiNe => "is_some()",
+                _ => return,
+            };
+            let other = if is_none_path(right) {
+                left
+            } else if is_none_path(left) {
+                right
+            } else {
+                return;
+            };
+            if !match_type(cx, cx.tcx.expr_ty(other), &OPTION_PATH) {
+                return;
+            }
+            span_lint_and_then(cx,
+                               CMP_OPTION_NONE,
+                               expr.span,
+                               &format!("comparing an `Option` to `None`, consider using `{}`", method),
+                               |db| {
+                                   db.span_suggestion(expr.span,
+                                                      "try this",
+                                                      format!("{}.{}", snippet(cx, other.span, ".."), method));
+                               });
+        }
+    }
+}
+
+/// Is this expression the `None` path, possibly qualified as in `Option::None`?
+fn is_none_path(expr: &Expr) -> bool {
+    if let ExprPath(None, ref path) = expr.node {
+        path.segments.last().map_or(false, |seg| seg.identifier.name.as_str() == "None")
+    } else {
+        false
+    }
+}
+
+/// **What it does:** This lint checks for `.unwrap()` on the result of `write!` or `writeln!`
+/// into a `String` or a `Vec<u8>`.
+///
+/// **Why is this bad?** Writing to these in-memory buffers never fails, so the `unwrap()` is just
+/// noise which makes the reader wonder what could go wrong.
+///
+/// **Known problems:** None
+///
+/// **Example:** `write!(s, "{}", x).unwrap()`
+declare_lint!(pub INFALLIBLE_WRITE_UNWRAP, Warn,
+              "using `unwrap()` on the result of `write!` to a `String` or `Vec<u8>`, which cannot fail");
+
+#[derive(Copy,Clone)]
+pub struct InfallibleWriteUnwrap;
+
+impl LintPass for InfallibleWriteUnwrap {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(INFALLIBLE_WRITE_UNWRAP)
+    }
+}
+
+impl LateLintPass for InfallibleWriteUnwrap {
+    fn check_expr(&mut self, cx: &LateContext, expr: &Expr) {
+        if_let_chain! {[
+            let ExprMethodCall(ref name, _, ref unwrap_args) = expr.node,
+            name.node.as_str() == "unwrap",
+            let ExprMethodCall(ref write_name, _, ref write_args) = unwrap_args[0].node,
+            write_name.node.as_str() == "write_fmt",
+            is_expn_of(cx, unwrap_args[0].span, "write").is_some()
+        ], {
+            let dest_ty = walk_ptrs_ty(cx.tcx.expr_ty(&write_args[0]));
+            if match_type(cx, dest_ty, &STRING_PATH) || match_type(cx, dest_ty, &VEC_PATH) {
+                span_note_and_lint(cx,
+                                   INFALLIBLE_WRITE_UNWRAP,
+                                   expr.span,
+                                   "called `unwrap()` on the result of writing to an in-memory buffer",
+                                   write_args[0].span,
+                                   "writing to a `String` or a `Vec<u8>` never fails, so the `unwrap()` \
+                                    can never panic");
+            }
+        }}
+    }
+}
+
+/// **What it does:** This lint checks for integer divisions whose result is immediately cast to a
+/// float, as in `(a / b) as f64`.
+///
+/// **Why is this bad?** The division is done on integers and thus truncates the result before the
+/// cast, which is probably not what was intended if the result is wanted as a float.
+///
+/// **Known problems:** If the truncation is intended, the lint has to be allowed or the division
+/// moved out of the cast into a separate binding.
+///
+/// **Example:** `let r = (1 / 2) as f64;` gives `0.0`, whereas `1 as f64 / 2 as f64` gives `0.5`.
+declare_lint!(pub INTEGER_DIVISION_TO_FLOAT, Warn,
+              "casting the result of an integer division to a float, which truncates before the cast");
+
+#[derive(Copy,Clone)]
+pub struct IntegerDivisionToFloat;
+
+impl LintPass for IntegerDivisionToFloat {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(INTEGER_DIVISION_TO_FLOAT)
+    }
+}
+
+impl LateLintPass for IntegerDivisionToFloat {
+    fn check_expr(&mut self, cx: &LateContext, expr: &Expr) {
+        if_let_chain! {[
+            let ExprCast(ref inner, ref ty) = expr.node,
+            let ExprBinary(ref op, ref left, ref right) = inner.node,
+            op.node == BiDiv,
+            cx.tcx.expr_ty(inner).is_integral(),
+            cx.tcx.expr_ty(expr).is_floating_point(),
+            !in_macro(cx, expr.span)
+        ], {
+            let ty = snippet(cx, ty.span, "f64");
+            span_lint_and_then(cx,
+                               INTEGER_DIVISION_TO_FLOAT,
+                               expr.span,
+                               "integer division casted to a float, the result is truncated before the cast",
+                               |db| {
+                                   db.span_suggestion(expr.span,
+                                                      "cast the operands instead",
+                                                      format!("{} as {} / {} as {}",
+                                                              cast_operand(cx, left),
+                                                              ty,
+                                                              cast_operand(cx, right),
+                                                              ty));
+                               });
+        }}
+    }
+}
+
+/// Get a snippet of `expr` suitable as the left-hand side of an `as` cast.
+fn cast_operand(cx: &LateContext, expr: &Expr) -> String {
+    let snip = snippet(cx, expr.span, "..");
+    match expr.node {
+        ExprLit(_) | ExprPath(..) | ExprField(..) | ExprTupField(..) | ExprCall(..) | ExprMethodCall(..) |
+        ExprIndex(..) => snip.into_owned(),
+        _ => format!("({})", snip),
+    }
+}
+
+/// **What it does:** This lint checks for arguments of the form `&x.clone()`, where the cloned
+/// value is only borrowed by the callee.
+///
+/// **Why is this bad?** The clone is a wasted allocation or copy, the callee could borrow `x`
+/// directly.
+///
+/// **Known problems:** None
+///
+/// **Example:** `foo(&s.clone())` could be `foo(&s)`
+declare_lint!(pub NEEDLESS_CLONE_ARG, Warn,
+              "passing a reference to a clone as an argument, where a reference to the original would do");
+
+#[derive(Copy,Clone)]
+pub struct NeedlessCloneArg;
+
+impl LintPass for NeedlessCloneArg {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(NEEDLESS_CLONE_ARG)
+    }
+}
+
+impl LateLintPass for NeedlessCloneArg {
+    fn check_expr(&mut self, cx: &LateContext, expr: &Expr) {
+        if in_macro(cx, expr.span) {
+            return;
+        }
+
+        match expr.node {
+            ExprCall(_, ref args) => {
+                for arg in args {
+                    check_clone_arg(cx, arg);
+                }
+            }
+            ExprMethodCall(_, _, ref args) => {
+                // the receiver is not an argument of interest, `(&x.clone()).foo()` is already weird
+                for arg in &args[1..] {
+                    check_clone_arg(cx, arg);
+                }
+            }
+            _ => (),
+        }
+    }
+}
+
+fn check_clone_arg(cx: &LateContext, arg: &Expr) {
+    if_let_chain! {[
+        let ExprAddrOf(MutImmutable, ref clone) = arg.node,
+        let ExprMethodCall(ref name, _, ref clone_args) = clone.node,
+        name.node.as_str() == "clone",
+        clone_args.len() == 1,
+        match_trait_method(cx, clone, &["core", "clone", "Clone"])
+    ], {
+        let recv = &clone_args[0];
+        let recv_ty = cx.tcx.expr_ty(recv);
+        let cloned_ty = cx.tcx.expr_ty(clone);
+
+        let sugg = if recv_ty == cloned_ty {
+            format!("&{}", snippet(cx, recv.span, ".."))
+        } else {
+            match recv_ty.sty {
+                ty::TyRef(_, ty::TypeAndMut { ty: inner, .. }) if inner == cloned_ty => {
+                    snippet(cx, recv.span, "..").into_owned()
+                }
+                _ => return,
+            }
+        };
+
+        span_lint_and_then(cx,
+                           NEEDLESS_CLONE_ARG,
+                           arg.span,
+                           "this argument is a reference to a clone, which is dropped right after the call",
+                           |db| {
+                               db.span_suggestion(arg.span, "borrow the original value instead", sugg);
+                           });
+    }}
+}
+
+/// **What it does:** This lint checks for calls to `std::process::exit`, `std::process::abort`
+/// and `panic!` in library crates.
+///
+/// **Why is this bad?** A library cannot know whether terminating is acceptable for the program
+/// using it, and the caller has no way to recover. Returning an error lets it decide.
+///
+/// **Known problems:** Panics are sometimes the right thing to do in a library, e.g. for
+/// violated invariants, which is why this lint is `Allow` by default.
+///
+/// **Example:** `if !valid { std::process::exit(1); }` in a function of a library
+declare_lint!(pub EXIT_IN_LIBRARY, Allow,
+              "calling `process::exit`, `process::abort` or `panic!` in a library crate");
+
+#[derive(Copy,Clone)]
+pub struct ExitInLibrary;
+
+impl LintPass for ExitInLibrary {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(EXIT_IN_LIBRARY)
+    }
+}
+
+impl LateLintPass for ExitInLibrary {
+    fn check_expr(&mut self, cx: &LateContext, expr: &Expr) {
+        if_let_chain! {[
+            let ExprCall(ref fun, _) = expr.node,
+            let ExprPath(None, ref path) = fun.node,
+            !cx.sess().crate_types.borrow().contains(&CrateTypeExecutable)
+        ], {
+            let (span, what) = if match_path(path, &BEGIN_UNWIND) || match_path(path, &BEGIN_UNWIND_FMT) {
+                match is_expn_of(cx, expr.span, "panic") {
+                    Some(span) => (span, "`panic!`"),
+                    None => return,
+                }
+            } else {
+                let def_id = match cx.tcx.def_map.borrow().get(&fun.id) {
+                    Some(def) => def.def_id(),
+                    None => return,
+                };
+                if match_def_path(cx, def_id, &["std", "process", "exit"]) {
+                    (expr.span, "`process::exit`")
+                } else if match_def_path(cx, def_id, &["std", "process", "abort"]) {
+                    (expr.span, "`process::abort`")
+                } else {
+                    return;
+                }
+            };
+
+            // only lint what was written by the user, not e.g. the `panic!` in `assert!`
+            if in_macro(cx, span) {
+                return;
+            }
+
+            span_help_and_lint(cx,
+                               EXIT_IN_LIBRARY,
+                               span,
+                               &format!("calling {} in a library", what),
+                               "consider returning an error to the caller instead");
+        }}
+    }
+}
+
+/// **What it does:** This lint checks for `let _ = x;` where `x` is of a `#[must_use]` type like
+/// `Result`.
+///
+/// **Why is this bad?** Binding to `_` silences the `unused_must_use` warning without saying why
+/// the value can be ignored, so errors get lost silently.
+///
+/// **Known problems:** Sometimes ignoring an error is really the right thing to do.
+///
+/// **Example:** `let _ = file.write_all(b"data");`
+declare_lint!(pub LET_UNDERSCORE_MUST_USE, Allow,
+              "discarding a `#[must_use]` value like a `Result` with `let _ = ...`");
+
+#[derive(Copy,Clone)]
+pub struct LetUnderscoreMustUse;
+
+impl LintPass for LetUnderscoreMustUse {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(LET_UNDERSCORE_MUST_USE)
+    }
+}
+
+impl LateLintPass for LetUnderscoreMustUse {
+    fn check_local(&mut self, cx: &LateContext, local: &Local) {
+        if_let_chain! {[
+            let PatKind::Wild = local.pat.node,
+            let Some(ref init) = local.init,
+            !in_macro(cx, local.span),
+            is_must_use(cx, cx.tcx.expr_ty(init))
+        ], {
+            let note = if match_type(cx, cx.tcx.expr_ty(init), &RESULT_PATH) {
+                "consider handling the error, or use `.ok()` to show that it is ignored on purpose"
+            } else {
+                "consider using the value, or explain why it can be ignored"
+            };
+            span_note_and_lint(cx,
+                               LET_UNDERSCORE_MUST_USE,
+                               local.span,
+                               "discarding a value of a `#[must_use]` type",
+                               init.span,
+                               note);
+        }}
+    }
+}
+
+fn is_must_use(cx: &LateContext, ty: ty::Ty) -> bool {
+    match ty.sty {
+        ty::TyEnum(def, _) | ty::TyStruct(def, _) => attr::contains_name(&cx.tcx.get_attrs(def.did), "must_use"),
+        _ => false,
+    }
+}
+
+/// **What it does:** This lint checks for `.abs()` calls on values which cannot be negative, like
+/// an unsigned integer cast to a float or the result of another `.abs()`.
+///
+/// **Why is this bad?** The call does nothing. It may also hide a mistake, e.g. the sign was
+/// supposed to be kept from a signed value.
+///
+/// **Known problems:** None
+///
+/// **Example:** `(x as f64).abs()` where `x: u32`
+declare_lint!(pub NEEDLESS_ABS, Warn,
+              "calling `.abs()` on a value which cannot be negative");
+
+#[derive(Copy,Clone)]
+pub struct NeedlessAbs;
+
+impl LintPass for NeedlessAbs {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(NEEDLESS_ABS)
+    }
+}
+
+impl LateLintPass for NeedlessAbs {
+    fn check_expr(&mut self, cx: &LateContext, expr: &Expr) {
+        if_let_chain! {[
+            let ExprMethodCall(ref name, _, ref args) = expr.node,
+            name.node.as_str() == "abs",
+            args.len() == 1,
+            !in_macro(cx, expr.span)
+        ], {
+            let note = match args[0].node {
+                ExprCast(ref inner, _) if cx.tcx.expr_ty(&args[0]).is_floating_point() => {
+                    if let ty::TyUint(_) = cx.tcx.expr_ty(inner).sty {
+                        "the value is cast from an unsigned integer"
+                    } else {
+                        return;
+                    }
+                }
+                ExprMethodCall(ref name, _, ref inner_args) if name.node.as_str() == "abs" && inner_args.len() == 1 => {
+                    "the value is already the result of `.abs()`"
+                }
+                _ => return,
+            };
+            span_note_and_lint(cx,
+                               NEEDLESS_ABS,
+                               expr.span,
+                               "this call to `.abs()` does nothing, the value cannot be negative",
+                               args[0].span,
+                               note);
+        }}
+    }
+}
+
+/// **What it does:** This lint checks for comparisons of the result of `.cmp(..)` or
+/// `.partial_cmp(..)` to an `Ordering` variant, as in `a.cmp(&b) == Ordering::Less`.
+///
+/// **Why is this bad?** The comparison operators say the same thing and are easier to read.
+///
+/// **Known problems:** None
+///
+/// **Example:** `a.cmp(&b) == Ordering::Less` could be `a < b`
+declare_lint!(pub ORDERING_COMPARISON, Warn,
+              "comparing the result of `.cmp(..)` to an `Ordering` variant instead of using a comparison operator");
+
+#[derive(Copy,Clone)]
+pub struct OrderingComparison;
+
+impl LintPass for OrderingComparison {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(ORDERING_COMPARISON)
+    }
+}
+
+impl LateLintPass for OrderingComparison {
+    fn check_expr(&mut self, cx: &LateContext, expr: &Expr) {
+        if_let_chain! {[
+            let ExprBinary(ref op, ref left, ref right) = expr.node,
+            op.node == BiEq || op.node == BiNe,
+            !in_macro(cx, expr.span)
+        ], {
+            let (call, variant, in_some) = if let Some((variant, in_some)) = ordering_variant(cx, right) {
+                (left, variant, in_some)
+            } else if let Some((variant, in_some)) = ordering_variant(cx, left) {
+                (right, variant, in_some)
+            } else {
+                return;
+            };
+
+            if_let_chain! {[
+                let ExprMethodCall(ref name, _, ref args) = call.node,
+                args.len() == 2,
+                // `partial_cmp` returns an `Option<Ordering>`
+                if in_some {
+                    name.node.as_str() == "partial_cmp" && match_trait_method(cx, call, &["core", "cmp", "PartialOrd"])
+                } else {
+                    name.node.as_str() == "cmp" && match_trait_method(cx, call, &["core", "cmp", "Ord"])
+                }
+            ], {
+                let cmp_op = match (&*variant.as_str(), op.node, in_some) {
+                    ("Less", BiEq, _) => "<",
+                    ("Greater", BiEq, _) => ">",
+                    ("Equal", BiEq, _) => "==",
+                    // `!=` only gives the opposite operator for a total order
+                    ("Less", BiNe, false) => ">=",
+                    ("Greater", BiNe, false) => "<=",
+                    ("Equal", BiNe, false) => "!=",
+                    _ => return,
+                };
+                let other = if let ExprAddrOf(_, ref other) = args[1].node {
+                    cast_operand(cx, other)
+                } else {
+                    format!("*{}", cast_operand(cx, &args[1]))
+                };
+                span_lint_and_then(cx,
+                                   ORDERING_COMPARISON,
+                                   expr.span,
+                                   &format!("comparing the result of `.{}()` to an `Ordering`", name.node),
+                                   |db| {
+                                       db.span_suggestion(expr.span,
+                                                          "use a comparison operator instead",
+                                                          format!("{} {} {}",
+                                                                  cast_operand(cx, &args[0]),
+                                                                  cmp_op,
+                                                                  other));
+                                   });
+            }}
+        }}
+    }
+}
+
+/// Get the variant of an `Ordering` path, or of `Some(<Ordering path>)`, and whether it is in a
+/// `Some`.
+fn ordering_variant(cx: &LateContext, expr: &Expr) -> Option<(Name, bool)> {
+    match expr.node {
+        ExprPath(None, ref path) if match_type(cx, cx.tcx.expr_ty(expr), &ORDERING_PATH) => {
+            path.segments.last().map(|segment| (segment.identifier.name, false))
+        }
+        ExprCall(ref fun, ref args) if args.len() == 1 => {
+            if let ExprPath(None, ref path) = fun.node {
+                if match_path(path, &["Some"]) {
+                    return ordering_variant(cx, &args[0]).and_then(|(variant, in_some)| {
+                        if in_some {
+                            None
+                        } else {
+                            Some((variant, true))
+                        }
+                    });
+                }
+            }
+            None
+        }
+        _ => None,
+    }
+}
+
 /// Heuristic to see if an expression is used. Should be compatible with `unused_variables`'s idea
 /// of what it means for an expression to be "used".
 fn is_used(cx: &LateContext, expr: &Expr) -> bool {
@@ -440,3 +955,132 @@ fn in_attributes_expansion(cx: &LateContext, expr: &Expr) -> bool {
         })
     })
 }
+
+/// **What it does:** This lint checks for divisions and remainders of unsigned integers by a
+/// constant power of two, like `x / 8` or `x % 16`.
+///
+/// **Why is this bad?** It is not, the compiler already turns these into a shift or a mask. Some
+/// code bases prefer spelling out the bit operations though.
+///
+/// **Known problems:** The bit operations are often less readable than the arithmetic.
+///
+/// **Example:** `x / 8` could be `x >> 3` and `x % 16` could be `x & 15`
+declare_lint!(pub POWER_OF_TWO_DIVISOR, Allow,
+              "dividing an unsigned integer by a power of two, which could be a shift or a mask");
+
+#[derive(Copy,Clone)]
+pub struct PowerOfTwoDivisor;
+
+impl LintPass for PowerOfTwoDivisor {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(POWER_OF_TWO_DIVISOR)
+    }
+}
+
+impl LateLintPass for PowerOfTwoDivisor {
+    fn check_expr(&mut self, cx: &LateContext, expr: &Expr) {
+        if_let_chain! {[
+            let ExprBinary(ref op, ref left, ref right) = expr.node,
+            op.node == BiDiv || op.node == BiRem,
+            !in_macro(cx, expr.span),
+            let ty::TyUint(_) = cx.tcx.expr_ty(left).sty,
+            let Some((Constant::Int(value, _, Sign::Plus), _)) = constant(cx, right),
+            value > 1 && value.is_power_of_two()
+        ], {
+            let (msg, note) = if op.node == BiDiv {
+                ("this division by a power of two could be a shift",
+                 format!("this is the same as shifting right by {}", value.trailing_zeros()))
+            } else {
+                ("this remainder by a power of two could be a mask",
+                 format!("this is the same as masking with `{}`", value - 1))
+            };
+            span_note_and_lint(cx, POWER_OF_TWO_DIVISOR, expr.span, msg, expr.span, &note);
+        }}
+    }
+}
+
+/// **What it does:** This lint checks for comparisons and `assert_eq!`s of
+/// `mem::size_of::<T>()` or `mem::size_of_val(..)` against a literal.
+///
+/// **Why is this bad?** The layout of a type is not guaranteed unless it is `#[repr(C)]` or
+/// `#[repr(packed)]`, and the size of many types depends on the platform, so such checks are
+/// brittle.
+///
+/// **Known problems:** The size of a `#[repr(C)]` type is well defined, this lint does not check
+/// for it.
+///
+/// **Example:** `assert_eq!(mem::size_of::<Foo>(), 16);`
+declare_lint!(pub SIZE_OF_LITERAL_COMPARISON, Allow,
+              "comparing `mem::size_of::<T>()` to a hardcoded number");
+
+#[derive(Copy,Clone)]
+pub struct SizeOfLiteralComparison;
+
+impl LintPass for SizeOfLiteralComparison {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(SIZE_OF_LITERAL_COMPARISON)
+    }
+}
+
+impl LateLintPass for SizeOfLiteralComparison {
+    fn check_expr(&mut self, cx: &LateContext, expr: &Expr) {
+        match expr.node {
+            ExprBinary(ref op, ref left, ref right) if is_comparison_binop(op.node) => {
+                if !in_macro(cx, expr.span) {
+                    check_size_of_literal(cx, expr.span, left, right);
+                }
+            }
+            // `assert_eq!(a, b)` expands to `match (&a, &b) { .. }`
+            ExprMatch(ref scrutinee, _, _) => {
+                if_let_chain! {[
+                    let Some(span) = is_expn_of(cx, expr.span, "assert_eq"),
+                    let ExprTup(ref values) = scrutinee.node,
+                    values.len() == 2,
+                    let ExprAddrOf(_, ref left) = values[0].node,
+                    let ExprAddrOf(_, ref right) = values[1].node,
+                    !in_macro(cx, span)
+                ], {
+                    check_size_of_literal(cx, span, left, right);
+                }}
+            }
+            _ => (),
+        }
+    }
+}
+
+fn check_size_of_literal(cx: &LateContext, span: Span, left: &Expr, right: &Expr) {
+    let call = if is_size_of_call(cx, left) && is_int_literal(right) {
+        left
+    } else if is_size_of_call(cx, right) && is_int_literal(left) {
+        right
+    } else {
+        return;
+    };
+    span_note_and_lint(cx,
+                       SIZE_OF_LITERAL_COMPARISON,
+                       span,
+                       "comparing the size of a type to a hardcoded number",
+                       call.span,
+                       "the size depends on the platform and on the layout chosen by the compiler, unless the type \
+                        is `#[repr(C)]` or `#[repr(packed)]`");
+}
+
+fn is_size_of_call(cx: &LateContext, expr: &Expr) -> bool {
+    if let ExprCall(ref fun, _) = expr.node {
+        if let Some(def) = cx.tcx.def_map.borrow().get(&fun.id) {
+            let def_id = def.def_id();
+            return match_def_path(cx, def_id, &["core", "mem", "size_of"]) ||
+                   match_def_path(cx, def_id, &["core", "mem", "size_of_val"]);
+        }
+    }
+    false
+}
+
+fn is_int_literal(expr: &Expr) -> bool {
+    if let ExprLit(ref lit) = expr.node {
+        if let LitKind::Int(..) = lit.node {
+            return true;
+        }
+    }
+    false
+}
@@ -5,8 +5,8 @@
 use rustc::lint::*;
 use rustc_front::hir::*;
 use syntax::ast::LitKind;
-use syntax::codemap::Spanned;
-use utils::{span_lint, span_lint_and_then, snippet};
+use syntax::codemap::{Span, Spanned};
+use utils::{get_parent_expr, in_macro, is_expn_of, span_lint, span_lint_and_then, snippet};
 
 /// **What it does:** This lint checks for expressions of the form `if c { true } else { false }` (or vice versa) and suggest using the condition directly.
 ///
@@ -28,7 +28,7 @@ declare_lint! {
 ///
 /// **Known problems:** None.
 ///
-/// **Example:** `if x == true { }` could be `if x { }`
+/// **Example:** `if x == true { }` could be `if x { }`, `assert!(x == false)` could be `assert!(!x)`
 declare_lint! {
     pub BOOL_COMPARISON,
     Warn,
@@ -48,49 +48,95 @@ impl LintPass for NeedlessBool {
 impl LateLintPass for NeedlessBool {
     fn check_expr(&mut self, cx: &LateContext, e: &Expr) {
         if let ExprIf(ref pred, ref then_block, Some(ref else_expr)) = e.node {
-            match (fetch_bool_block(then_block), fetch_bool_expr(else_expr)) {
-                (Some(true), Some(true)) => {
+            let (then_value, else_value, ret) = match (fetch_bool_block(then_block), fetch_bool_expr(else_expr)) {
+                (Some(then_value), Some(else_value)) => (then_value, else_value, ""),
+                _ => {
+                    match (fetch_return_block(then_block), fetch_return_expr(else_expr)) {
+                        (Some(then_value), Some(else_value)) => (then_value, else_value, "return "),
+                        _ => return,
+                    }
+                }
+            };
+            match (then_value, else_value) {
+                (true, true) => {
                     span_lint(cx,
                               NEEDLESS_BOOL,
                               e.span,
                               "this if-then-else expression will always return true");
                 }
-                (Some(false), Some(false)) => {
+                (false, false) => {
                     span_lint(cx,
                               NEEDLESS_BOOL,
                               e.span,
                               "this if-then-else expression will always return false");
                 }
-                (Some(true), Some(false)) => {
-                    let pred_snip = snippet(cx, pred.span, "..");
-                    let hint = if pred_snip == ".." {
-                        "its predicate".into()
-                    } else {
-                        format!("`{}`", pred_snip)
-                    };
+                (true, false) | (false, true) => {
                     span_lint(cx,
                               NEEDLESS_BOOL,
                               e.span,
-                              &format!("you can reduce this if-then-else expression to just {}", hint));
+                              &format!("you can reduce this if-then-else expression to just {}",
+                                       reduced_hint(cx, pred, !then_value, ret)));
                 }
-                (Some(false), Some(true)) => {
-                    let pred_snip = snippet(cx, pred.span, "..");
-                    let hint = if pred_snip == ".." {
-                        "`!` and its predicate".into()
+            }
+        }
+    }
+
+    fn check_block(&mut self, cx: &LateContext, block: &Block) {
+        // `if c { return true; } return false;`
+        for (i, stmt) in block.stmts.iter().enumerate() {
+            let next = match block.stmts.get(i + 1).map(|next| &next.node) {
+                Some(&StmtSemi(ref next, _)) => next,
+                Some(_) => continue,
+                None => {
+                    if let Some(ref next) = block.expr {
+                        next
                     } else {
-                        format!("`!{}`", pred_snip)
-                    };
-                    span_lint(cx,
-                              NEEDLESS_BOOL,
-                              e.span,
-                              &format!("you can reduce this if-then-else expression to just {}", hint));
+                        return;
+                    }
                 }
-                _ => (),
-            }
+            };
+
+            let if_expr = match stmt.node {
+                StmtExpr(ref if_expr, _) | StmtSemi(ref if_expr, _) => if_expr,
+                StmtDecl(..) => continue,
+            };
+
+            if_let_chain! {[
+                let ExprIf(ref pred, ref then_block, None) = if_expr.node,
+                let Some(then_value) = fetch_return_block(then_block),
+                let Some(next_value) = fetch_return_expr(next),
+                then_value != next_value,
+                !in_macro(cx, if_expr.span)
+            ], {
+                span_lint(cx,
+                          NEEDLESS_BOOL,
+                          Span { hi: next.span.hi, ..if_expr.span },
+                          &format!("you can reduce this `if` and the following `return` to just {}",
+                                   reduced_hint(cx, pred, !then_value, "return ")));
+            }}
         }
     }
 }
 
+/// The hint for replacing an `if` by its predicate `pred`, possibly `negate`d, after `ret`.
+fn reduced_hint(cx: &LateContext, pred: &Expr, negate: bool, ret: &str) -> String {
+    let pred_snip = snippet(cx, pred.span, "..");
+    let not = if negate {
+        "!"
+    } else {
+        ""
+    };
+    if pred_snip == ".." {
+        if negate {
+            "`!` and its predicate".into()
+        } else {
+            "its predicate".into()
+        }
+    } else {
+        format!("`{}{}{}`", ret, not, pred_snip)
+    }
+}
+
 #[derive(Copy,Clone)]
 pub struct BoolComparison;
 
@@ -102,7 +148,33 @@ impl LintPass for BoolComparison {
 
 impl LateLintPass for BoolComparison {
     fn check_expr(&mut self, cx: &LateContext, e: &Expr) {
+        if let Some((assert_span, cond)) = assert_condition(cx, e) {
+            if let ExprBinary(Spanned{ node: BiEq, .. }, ref left_side, ref right_side) = cond.node {
+                let hint = match (fetch_bool_expr(left_side), fetch_bool_expr(right_side)) {
+                    (Some(true), None) => snippet(cx, right_side.span, "..").into_owned(),
+                    (None, Some(true)) => snippet(cx, left_side.span, "..").into_owned(),
+                    (Some(false), None) => format!("!{}", snippet(cx, right_side.span, "..")),
+                    (None, Some(false)) => format!("!{}", snippet(cx, left_side.span, "..")),
+                    _ => return,
+                };
+                span_lint_and_then(cx,
+                                   BOOL_COMPARISON,
+                                   assert_span,
+                                   "equality checks against a boolean in `assert!` are unnecesary",
+                                   |db| {
+                                       db.span_suggestion(cond.span, "try simplifying it as shown:", hint);
+                                   });
+            }
+            return;
+        }
         if let ExprBinary(Spanned{ node: BiEq, .. }, ref left_side, ref right_side) = e.node {
+            // comparisons in `assert!` are handled above, on the expanded `if`
+            let in_assert = get_parent_expr(cx, e)
+                                .and_then(|parent| get_parent_expr(cx, parent))
+                                .map_or(false, |grandparent| assert_condition(cx, grandparent).is_some());
+            if in_assert {
+                return;
+            }
             match (fetch_bool_expr(left_side), fetch_bool_expr(right_side)) {
                 (Some(true), None) => {
                     let hint = snippet(cx, right_side.span, "..").into_owned();
@@ -150,6 +222,19 @@ impl LateLintPass for BoolComparison {
     }
 }
 
+/// If `expr` is the `if !cond { panic!(..) }` that `assert!(cond)` expands to, returns the span of the `assert!`
+/// call and `cond`.
+fn assert_condition<'e>(cx: &LateContext, expr: &'e Expr) -> Option<(Span, &'e Expr)> {
+    if let ExprIf(ref cond, _, None) = expr.node {
+        if let ExprUnary(UnNot, ref cond) = cond.node {
+            if let Some(span) = is_expn_of(cx, expr.span, "assert") {
+                return Some((span, cond));
+            }
+        }
+    }
+    None
+}
+
 fn fetch_bool_block(block: &Block) -> Option<bool> {
     if block.stmts.is_empty() {
         block.expr.as_ref().and_then(|e| fetch_bool_expr(e))
@@ -158,6 +243,28 @@ fn fetch_bool_block(block: &Block) -> Option<bool> {
     }
 }
 
+/// Get the value of a block only made of `return true` or `return false`.
+fn fetch_return_block(block: &Block) -> Option<bool> {
+    match (block.stmts.len(), &block.expr) {
+        (0, &Some(ref e)) => fetch_return_expr(e),
+        (1, &None) => {
+            match block.stmts[0].node {
+                StmtExpr(ref e, _) | StmtSemi(ref e, _) => fetch_return_expr(e),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+fn fetch_return_expr(expr: &Expr) -> Option<bool> {
+    match expr.node {
+        ExprBlock(ref block) => fetch_return_block(block),
+        ExprRet(Some(ref value)) => fetch_bool_expr(value),
+        _ => None,
+    }
+}
+
 fn fetch_bool_expr(expr: &Expr) -> Option<bool> {
     match expr.node {
         ExprBlock(ref block) => fetch_bool_block(block),
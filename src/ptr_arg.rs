@@ -1,15 +1,22 @@
-//! Checks for usage of &Vec[_] and &String
-//!
-//! This lint is **warn** by default
+//! Checks for usage of &Vec[_], &String and &Box<_> in arguments and return types, and for `Vec<u8>` arguments that are only read
 
 use rustc::front::map::NodeItem;
 use rustc::lint::*;
+use rustc::middle::expr_use_visitor::*;
+use rustc::middle::infer;
+use rustc::middle::mem_categorization::{cmt, Categorization};
+use rustc::middle::def::Def;
+use rustc::middle::subst::ParamSpace;
 use rustc::middle::ty;
+use rustc::util::nodemap::NodeSet;
 use rustc_front::hir::*;
+use rustc_front::intravisit::{FnKind, Visitor, walk_block, walk_expr};
+use syntax::ast::{NodeId, UintTy};
+use syntax::codemap::Span;
 use utils::{STRING_PATH, VEC_PATH};
-use utils::{span_lint, match_type};
+use utils::{get_parent_expr, match_type, snippet, span_lint, span_lint_and_then, span_note_and_lint, walk_ptrs_ty};
 
-/// **What it does:** This lint checks for function arguments of type `&String` or `&Vec` unless the references are mutable.
+/// **What it does:** This lint checks for function arguments of type `&String`, `&Vec` or `&Box` unless the references are mutable.
 ///
 /// **Why is this bad?** Requiring the argument to be of the specific size makes the function less useful for no benefit; slices in the form of `&[T]` or `&str` usually suffice and can be obtained from other types, too.
 ///
@@ -19,8 +26,37 @@ use utils::{span_lint, match_type};
 declare_lint! {
     pub PTR_ARG,
     Warn,
-    "fn arguments of the type `&Vec<...>` or `&String`, suggesting to use `&[...]` or `&str` \
-     instead, respectively"
+    "fn arguments of the type `&Vec<...>`, `&String` or `&Box<...>`, suggesting to use `&[...]`, \
+     `&str` or `&...` instead, respectively"
+}
+
+/// **What it does:** This lint checks for functions returning `&String` or `&Vec`.
+///
+/// **Why is this bad?** Returning a reference to the concrete container ties the callers to it, a
+/// slice in the form of `&[T]` or `&str` gives them the same access and can be changed more easily.
+///
+/// **Known problems:** None
+///
+/// **Example:** `fn names(&self) -> &Vec<String> { &self.names }`
+declare_lint! {
+    pub PTR_RET,
+    Warn,
+    "fn returning `&Vec<...>` or `&String`, suggesting to return `&[...]` or `&str` instead, respectively"
+}
+
+/// **What it does:** This lint checks for function arguments of type `Vec<u8>` taken by value
+/// which are only ever read in the function body.
+///
+/// **Why is this bad?** Parsing or hashing APIs rarely need to own their input. Taking `&[u8]`
+/// lets callers pass byte strings, arrays or parts of a buffer without allocating a new `Vec`.
+///
+/// **Known problems:** Sometimes taking ownership is a deliberate part of a public API.
+///
+/// **Example:** `fn checksum(data: Vec<u8>) -> u8 { data.iter().fold(0, |a, b| a ^ b) }`
+declare_lint! {
+    pub BYTE_VEC_ARG,
+    Allow,
+    "fn arguments of the type `Vec<u8>` which are only read, suggesting to use `&[u8]` instead"
 }
 
 #[derive(Copy,Clone)]
@@ -28,36 +64,36 @@ pub struct PtrArg;
 
 impl LintPass for PtrArg {
     fn get_lints(&self) -> LintArray {
-        lint_array!(PTR_ARG)
+        lint_array!(PTR_ARG, PTR_RET)
     }
 }
 
 impl LateLintPass for PtrArg {
     fn check_item(&mut self, cx: &LateContext, item: &Item) {
-        if let ItemFn(ref decl, _, _, _, _, _) = item.node {
-            check_fn(cx, decl);
+        if let ItemFn(ref decl, _, _, _, _, ref body) = item.node {
+            check_fn(cx, decl, Some(body));
         }
     }
 
     fn check_impl_item(&mut self, cx: &LateContext, item: &ImplItem) {
-        if let ImplItemKind::Method(ref sig, _) = item.node {
+        if let ImplItemKind::Method(ref sig, ref body) = item.node {
             if let Some(NodeItem(it)) = cx.tcx.map.find(cx.tcx.map.get_parent(item.id)) {
                 if let ItemImpl(_, _, _, Some(_), _, _) = it.node {
                     return; // ignore trait impls
                 }
             }
-            check_fn(cx, &sig.decl);
+            check_fn(cx, &sig.decl, Some(body));
         }
     }
 
     fn check_trait_item(&mut self, cx: &LateContext, item: &TraitItem) {
-        if let MethodTraitItem(ref sig, _) = item.node {
-            check_fn(cx, &sig.decl);
+        if let MethodTraitItem(ref sig, ref body) = item.node {
+            check_fn(cx, &sig.decl, body.as_ref().map(|b| &**b));
         }
     }
 }
 
-fn check_fn(cx: &LateContext, decl: &FnDecl) {
+fn check_fn(cx: &LateContext, decl: &FnDecl, body: Option<&Block>) {
     for arg in &decl.inputs {
         if let Some(ty) = cx.tcx.ast_ty_to_ty_cache.borrow().get(&arg.ty.id) {
             if let ty::TyRef(_, ty::TypeAndMut { ty, mutbl: MutImmutable }) = ty.sty {
@@ -73,8 +109,211 @@ fn check_fn(cx: &LateContext, decl: &FnDecl) {
                               arg.ty.span,
                               "writing `&String` instead of `&str` involves a new object where a slice will do. \
                                Consider changing the type to `&str`");
+                } else if let ty::TyBox(inner) = ty.sty {
+                    if body.map_or(false, |body| needs_box(cx, arg, body)) {
+                        continue;
+                    }
+                    span_lint_and_then(cx,
+                                       PTR_ARG,
+                                       arg.ty.span,
+                                       "writing `&Box<_>` instead of `&_` involves one more reference and cannot \
+                                        be used with values which are not boxed. Consider changing the type to `&_`",
+                                       |db| {
+                                           db.span_suggestion(arg.ty.span, "change this to", format!("&{}", inner));
+                                       });
+                }
+            }
+        }
+    }
+
+    if let Return(ref ret) = decl.output {
+        check_ret(cx, ret);
+    }
+}
+
+fn check_ret(cx: &LateContext, ret: &Ty) {
+    if_let_chain! {[
+        let TyRptr(ref lifetime, MutTy { ty: ref inner, mutbl: MutImmutable }) = ret.node,
+        let Some(ty) = cx.tcx.ast_ty_to_ty_cache.borrow().get(&ret.id),
+        let ty::TyRef(_, ty::TypeAndMut { ty, .. }) = ty.sty
+    ], {
+        let lifetime = lifetime.as_ref().map_or(String::new(), |lt| format!("{} ", lt.name));
+        let (msg, sugg) = if match_type(cx, ty, &VEC_PATH) {
+            let elem = match inner.node {
+                TyPath(_, ref path) => {
+                    match path.segments.last().map(|seg| &seg.parameters) {
+                        Some(&AngleBracketedParameters(ref params)) if params.types.len() == 1 => {
+                            snippet(cx, params.types[0].span, "_")
+                        }
+                        _ => "_".into(),
+                    }
+                }
+                _ => "_".into(),
+            };
+            ("returning `&Vec<_>` instead of `&[_]` ties the callers to the container type. Consider changing \
+              the type to `&[...]`",
+             format!("&{}[{}]", lifetime, elem))
+        } else if match_type(cx, ty, &STRING_PATH) {
+            ("returning `&String` instead of `&str` ties the callers to the container type. Consider changing the \
+              type to `&str`",
+             format!("&{}str", lifetime))
+        } else {
+            return;
+        };
+        span_lint_and_then(cx, PTR_RET, ret.span, msg, |db| {
+            db.span_suggestion(ret.span, "change this to", sugg);
+        });
+    }}
+}
+
+/// Check whether the `&Box<_>` argument is used as a `Box` (e.g. cloned) somewhere in `body`.
+fn needs_box(cx: &LateContext, arg: &Arg, body: &Block) -> bool {
+    if let PatKind::Ident(_, _, None) = arg.pat.node {
+        let mut visitor = BoxUseVisitor {
+            cx: cx,
+            id: arg.pat.id,
+            needs_box: false,
+        };
+        walk_block(&mut visitor, body);
+        visitor.needs_box
+    } else {
+        // destructuring patterns might use the box in any way
+        true
+    }
+}
+
+struct BoxUseVisitor<'a, 'tcx: 'a> {
+    cx: &'a LateContext<'a, 'tcx>,
+    id: NodeId,
+    needs_box: bool,
+}
+
+impl<'a, 'tcx: 'a> BoxUseVisitor<'a, 'tcx> {
+    /// A use needs the `Box` unless it is auto-dereferenced to the inner type.
+    fn check_use(&mut self, expr: &Expr) {
+        if let Some(parent) = get_parent_expr(self.cx, expr) {
+            if let ExprUnary(UnDeref, _) = parent.node {
+                self.check_use(parent);
+                return;
+            }
+        }
+        if let ty::TyBox(_) = walk_ptrs_ty(self.cx.tcx.expr_ty_adjusted(expr)).sty {
+            self.needs_box = true;
+        }
+    }
+}
+
+impl<'v, 'a, 'tcx: 'a> Visitor<'v> for BoxUseVisitor<'a, 'tcx> {
+    fn visit_expr(&mut self, expr: &'v Expr) {
+        if let ExprPath(..) = expr.node {
+            let def = self.cx.tcx.def_map.borrow().get(&expr.id).map(|d| d.full_def());
+            if let Some(Def::Local(_, id)) = def {
+                if id == self.id {
+                    self.check_use(expr);
+                }
+            }
+        }
+        walk_expr(self, expr);
+    }
+}
+
+#[derive(Copy,Clone)]
+pub struct ByteVecArg;
+
+impl LintPass for ByteVecArg {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(BYTE_VEC_ARG)
+    }
+}
+
+impl LateLintPass for ByteVecArg {
+    fn check_fn(&mut self, cx: &LateContext, kind: FnKind, decl: &FnDecl, body: &Block, _: Span, id: NodeId) {
+        match kind {
+            FnKind::ItemFn(..) => (),
+            FnKind::Method(..) => {
+                if let Some(NodeItem(it)) = cx.tcx.map.find(cx.tcx.map.get_parent(id)) {
+                    if let ItemImpl(_, _, _, Some(_), _, _) = it.node {
+                        return; // ignore trait impls, the signature is not ours to change
+                    }
                 }
             }
+            FnKind::Closure => return,
+        }
+
+        let mut set = NodeSet();
+        for arg in &decl.inputs {
+            if let PatKind::Ident(BindByValue(MutImmutable), _, None) = arg.pat.node {
+                if is_byte_vec(cx, cx.tcx.pat_ty(&arg.pat)) {
+                    set.insert(arg.pat.id);
+                }
+            }
+        }
+        if set.is_empty() {
+            return;
+        }
+
+        let param_env = ty::ParameterEnvironment::for_item(cx.tcx, id);
+        let infcx = infer::new_infer_ctxt(cx.tcx, &cx.tcx.tables, Some(param_env));
+        let mut v = MoveDelegate { set: set };
+        {
+            let mut vis = ExprUseVisitor::new(&mut v, &infcx);
+            vis.walk_fn(decl, body);
+        }
+
+        for arg in &decl.inputs {
+            if v.set.contains(&arg.pat.id) {
+                span_note_and_lint(cx,
+                                   BYTE_VEC_ARG,
+                                   arg.ty.span,
+                                   "this argument is a `Vec<u8>` which is only read. Consider changing the type \
+                                    to `&[u8]`",
+                                   arg.ty.span,
+                                   "taking a slice does not force callers to allocate a `Vec` for their bytes");
+            }
+        }
+    }
+}
+
+fn is_byte_vec(cx: &LateContext, ty: ty::Ty) -> bool {
+    if let ty::TyStruct(_, substs) = ty.sty {
+        match_type(cx, ty, &VEC_PATH) && substs.types.get(ParamSpace::TypeSpace, 0).sty == ty::TyUint(UintTy::U8)
+    } else {
+        false
+    }
+}
+
+/// Removes every local from `set` which is moved out of, leaving the read-only ones.
+struct MoveDelegate {
+    set: NodeSet,
+}
+
+impl<'tcx> Delegate<'tcx> for MoveDelegate {
+    fn consume(&mut self, _: NodeId, _: Span, cmt: cmt<'tcx>, mode: ConsumeMode) {
+        if let Categorization::Local(lid) = cmt.cat {
+            if let Move(_) = mode {
+                self.set.remove(&lid);
+            }
+        }
+    }
+    fn matched_pat(&mut self, _: &Pat, _: cmt<'tcx>, _: MatchMode) {}
+    fn consume_pat(&mut self, _: &Pat, cmt: cmt<'tcx>, mode: ConsumeMode) {
+        if let Categorization::Local(lid) = cmt.cat {
+            if let Move(_) = mode {
+                self.set.remove(&lid);
+            }
+        }
+    }
+    fn borrow(&mut self, _: NodeId, _: Span, cmt: cmt<'tcx>, _: ty::Region, kind: ty::BorrowKind, _: LoanCause) {
+        if let Categorization::Local(lid) = cmt.cat {
+            if kind != ty::ImmBorrow {
+                self.set.remove(&lid);
+            }
+        }
+    }
+    fn decl_without_init(&mut self, _: NodeId, _: Span) {}
+    fn mutate(&mut self, _: NodeId, _: Span, cmt: cmt<'tcx>, _: MutateMode) {
+        if let Categorization::Local(lid) = cmt.cat {
+            self.set.remove(&lid);
         }
     }
 }
@@ -0,0 +1,149 @@
+//! lint on `else` blocks following an `if` block which always returns, breaks or continues
+
+use rustc::front::map::Node::{NodeExpr, NodeImplItem, NodeItem, NodeTraitItem};
+use rustc::lint::*;
+use rustc::middle::ty;
+use rustc_front::hir::*;
+use std::collections::HashSet;
+use syntax::ast::NodeId;
+
+use loops::loop_body;
+use utils::{in_macro, span_help_and_lint};
+
+/// **What it does:** This lint checks for `if` blocks which always `return`, `break`, `continue` or panic, followed
+/// by an `else` block.
+///
+/// **Why is this bad?** The `else` block is only reached when the `if` block is not taken anyway, so its content can
+/// follow the `if` directly, which saves a level of indentation.
+///
+/// **Known problems:** Moving the content of the `else` block out also moves its `let` bindings to the enclosing
+/// block, which extends their scope, e.g. a lock guard would be held longer. The final `if` of a function body is
+/// not linted, as `NEEDLESS_RETURN` already suggests dropping the `return` there.
+///
+/// **Example:**
+/// ```
+/// if x.is_empty() {
+///     return None;
+/// } else {
+///     foo(x)
+/// }
+/// ```
+declare_lint! {
+    pub REDUNDANT_ELSE, Warn,
+    "an `else` block after an `if` block which always returns, breaks or continues"
+}
+
+#[derive(Default)]
+pub struct RedundantElse {
+    /// The final statements or expressions of loop bodies.
+    loop_tails: HashSet<NodeId>,
+}
+
+impl LintPass for RedundantElse {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(REDUNDANT_ELSE)
+    }
+}
+
+impl LateLintPass for RedundantElse {
+    fn check_expr(&mut self, _: &LateContext, expr: &Expr) {
+        if let Some(block) = loop_body(expr) {
+            if let Some(tail) = block_tail(block) {
+                self.loop_tails.insert(tail.id);
+            }
+        }
+    }
+
+    fn check_block(&mut self, cx: &LateContext, block: &Block) {
+        // Only look at `if`s which are statements or the final expression of a block: flattening those is always
+        // possible, the `else` block's content just becomes the rest of the block.
+        for stmt in &block.stmts {
+            if let StmtExpr(ref expr, _) = stmt.node {
+                self.check_if(cx, expr);
+            } else if let StmtSemi(ref expr, _) = stmt.node {
+                self.check_if(cx, expr);
+            }
+        }
+        if let Some(ref expr) = block.expr {
+            if !is_fn_body(cx, block) {
+                self.check_if(cx, expr);
+            }
+        }
+    }
+}
+
+impl RedundantElse {
+    fn check_if(&self, cx: &LateContext, expr: &Expr) {
+        if let ExprIf(_, ref then, Some(ref els)) = expr.node {
+            if let ExprBlock(_) = els.node {
+                // a `continue` ending the loop body is linted by `NEEDLESS_CONTINUE` instead
+                if self.loop_tails.contains(&expr.id) && ends_with_continue(then) {
+                    return;
+                }
+                if !in_macro(cx, expr.span) && block_diverges(cx, then) {
+                    span_help_and_lint(cx,
+                                       REDUNDANT_ELSE,
+                                       els.span,
+                                       "redundant `else` block, the `if` block never falls through",
+                                       "remove the `else` and move its content after the `if` block");
+                }
+            }
+        }
+    }
+}
+
+/// Get the last statement or expression of a block.
+fn block_tail(block: &Block) -> Option<&Expr> {
+    if let Some(ref expr) = block.expr {
+        return Some(expr);
+    }
+    match block.stmts.last().map(|stmt| &stmt.node) {
+        Some(&StmtExpr(ref expr, _)) |
+        Some(&StmtSemi(ref expr, _)) => Some(expr),
+        _ => None,
+    }
+}
+
+/// Does the block end with an unlabeled `continue`?
+fn ends_with_continue(block: &Block) -> bool {
+    if let Some(&ExprAgain(None)) = block_tail(block).map(|expr| &expr.node) {
+        true
+    } else {
+        false
+    }
+}
+
+/// Is this block the body of a function or closure?
+fn is_fn_body(cx: &LateContext, block: &Block) -> bool {
+    match cx.tcx.map.find(cx.tcx.map.get_parent_node(block.id)) {
+        Some(NodeItem(_)) | Some(NodeImplItem(_)) | Some(NodeTraitItem(_)) => true,
+        Some(NodeExpr(expr)) => {
+            if let ExprClosure(..) = expr.node {
+                true
+            } else {
+                false
+            }
+        }
+        _ => false,
+    }
+}
+
+/// Does the last statement or expression of the block always leave it?
+fn block_diverges(cx: &LateContext, block: &Block) -> bool {
+    block_tail(block).map_or(false, |expr| expr_diverges(cx, expr))
+}
+
+fn expr_diverges(cx: &LateContext, expr: &Expr) -> bool {
+    match expr.node {
+        ExprRet(_) | ExprBreak(_) | ExprAgain(_) => true,
+        ExprBlock(ref block) => block_diverges(cx, block),
+        ExprCall(ref callee, _) => {
+            if let ty::TyBareFn(_, ty) = cx.tcx.node_id_to_type(callee.id).sty {
+                ty.sig.skip_binder().output.diverges()
+            } else {
+                false
+            }
+        }
+        _ => false,
+    }
+}
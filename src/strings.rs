@@ -3,12 +3,17 @@
 //! Note that since we have two lints where one subsumes the other, we try to
 //! disable the subsumed lint unless it has a higher level
 
+use consts::{constant, Constant};
 use rustc::lint::*;
+use rustc::middle::ty;
 use rustc_front::hir::*;
+use syntax::ast::RangeLimits;
 use syntax::codemap::Spanned;
-use utils::STRING_PATH;
+use utils::{STRING_PATH, VEC_PATH};
 use utils::SpanlessEq;
-use utils::{match_type, span_lint, walk_ptrs_ty, get_parent_expr};
+use utils::{match_path, match_trait_method, match_type, span_lint, walk_ptrs_ty, get_parent_expr};
+use utils::{get_single_expr, in_macro, is_integer_literal, method_chain_args, recover_for_loop, snippet,
+            span_help_and_lint, span_lint_and_then, unsugar_range, UnsugaredRange};
 
 /// **What it does:** This lint matches code of the form `x = x + y` (without `let`!).
 ///
@@ -64,6 +69,62 @@ declare_lint! {
     "calling `as_bytes` on a string literal; suggests using a byte string literal instead"
 }
 
+/// **What it does:** This lint matches loops of the form `for _ in 0..n { s.push(c) }`, where
+/// `s` is a `String` and `c` a `char` which does not change in the loop.
+///
+/// **Why is this bad?** `s.extend(std::iter::repeat(c).take(n))` says what is meant in one line.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+///
+/// ```
+/// for _ in 0..4 {
+///     s.push(' ');
+/// }
+/// ```
+declare_lint! {
+    pub REPEATED_CHAR_PUSH,
+    Warn,
+    "pushing the same `char` onto a `String` in a loop; suggests using `extend` with `iter::repeat` instead"
+}
+
+/// **What it does:** This lint matches `s.split(x).collect::<Vec<_>>()[n]` where `n` is a constant.
+///
+/// **Why is this bad?** Collecting all the parts into a `Vec` allocates just to get a single one of them, which
+/// `.nth(n)` gets directly.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+///
+/// ```
+/// let first = path.split('/').collect::<Vec<_>>()[0];
+/// ```
+declare_lint! {
+    pub SPLIT_COLLECT_INDEX,
+    Warn,
+    "indexing the `Vec` collected from a `split`; suggests using `.nth(n)` instead"
+}
+
+/// **What it does:** This lint matches `fold`s which only push every element onto a `String`, as in
+/// `.fold(String::new(), |mut acc, s| { acc.push_str(s); acc })`.
+///
+/// **Why is this bad?** `.collect::<String>()` does the same and says so.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+///
+/// ```
+/// let s = words.iter().fold(String::new(), |mut acc, w| { acc.push_str(w); acc });
+/// ```
+declare_lint! {
+    pub MANUAL_STRING_FOLD,
+    Allow,
+    "building a `String` with `fold`; suggests using `.collect::<String>()` instead"
+}
+
 #[derive(Copy, Clone)]
 pub struct StringAdd;
 
@@ -90,10 +151,35 @@ impl LateLintPass for StringAdd {
                         }
                     }
                 }
-                span_lint(cx,
-                          STRING_ADD,
-                          e.span,
-                          "you added something to a string. Consider using `String::push_str()` instead");
+                // chains are reported once, on their last `+`
+                if let Some(parent) = get_parent_expr(cx, e) {
+                    if let ExprBinary(Spanned{ node: BiAdd, .. }, ref parent_left, _) = parent.node {
+                        if parent_left.id == e.id {
+                            return;
+                        }
+                    }
+                }
+                let mut db = span_lint(cx,
+                                       STRING_ADD,
+                                       e.span,
+                                       "you added something to a string. Consider using `String::push_str()` \
+                                        instead");
+                let terms = string_add_terms(cx, e);
+                if terms.len() >= 3 {
+                    let args = terms.iter()
+                                    .map(|term| {
+                                        let term = match term.node {
+                                            ExprAddrOf(_, ref inner) => &**inner,
+                                            _ => *term,
+                                        };
+                                        snippet(cx, term.span, "..").into_owned()
+                                    })
+                                    .collect::<Vec<_>>();
+                    db.span_note(e.span,
+                                 &format!("consider using `format!(\"{}\", {})` to build the string at once",
+                                          terms.iter().map(|_| "{}").collect::<String>(),
+                                          args.join(", ")));
+                }
             }
         } else if let ExprAssign(ref target, ref src) = e.node {
             if is_string(cx, target) && is_add(cx, src, target) {
@@ -107,6 +193,18 @@ impl LateLintPass for StringAdd {
     }
 }
 
+/// Get the operands of a chain of `+` starting with a `String`, e.g. `[a, &b, &c]` for `a + &b + &c`.
+fn string_add_terms<'e>(cx: &LateContext, e: &'e Expr) -> Vec<&'e Expr> {
+    if let ExprBinary(Spanned{ node: BiAdd, .. }, ref left, ref right) = e.node {
+        if is_string(cx, left) {
+            let mut terms = string_add_terms(cx, left);
+            terms.push(right);
+            return terms;
+        }
+    }
+    vec![e]
+}
+
 fn is_string(cx: &LateContext, e: &Expr) -> bool {
     match_type(cx, walk_ptrs_ty(cx.tcx.expr_ty(e)), &STRING_PATH)
 }
@@ -134,7 +232,6 @@ impl LateLintPass for StringLitAsBytes {
     fn check_expr(&mut self, cx: &LateContext, e: &Expr) {
         use std::ascii::AsciiExt;
         use syntax::ast::LitKind;
-        use utils::{snippet, in_macro};
 
         if let ExprMethodCall(ref name, _, ref args) = e.node {
             if name.node.as_str() == "as_bytes" {
@@ -153,3 +250,157 @@ impl LateLintPass for StringLitAsBytes {
         }
     }
 }
+
+#[derive(Copy, Clone)]
+pub struct RepeatedCharPush;
+
+impl LintPass for RepeatedCharPush {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(REPEATED_CHAR_PUSH)
+    }
+}
+
+impl LateLintPass for RepeatedCharPush {
+    fn check_expr(&mut self, cx: &LateContext, expr: &Expr) {
+        if_let_chain! {[
+            let Some((pat, arg, body)) = recover_for_loop(expr),
+            let Some(UnsugaredRange { start: Some(ref start), end: Some(ref end), limits: RangeLimits::HalfOpen }) =
+                unsugar_range(arg),
+            is_integer_literal(start, 0),
+            let Some(push) = get_single_expr(body),
+            let ExprMethodCall(ref name, _, ref push_args) = push.node,
+            name.node.as_str() == "push",
+            push_args.len() == 2,
+            match_type(cx, walk_ptrs_ty(cx.tcx.expr_ty(&push_args[0])), &STRING_PATH),
+            cx.tcx.expr_ty(&push_args[1]).sty == ty::TyChar,
+            is_loop_invariant(pat, &push_args[1])
+        ], {
+            span_help_and_lint(cx,
+                               REPEATED_CHAR_PUSH,
+                               expr.span,
+                               "pushing the same `char` onto a `String` in a loop",
+                               &format!("consider using `{}.extend(std::iter::repeat({}).take({}))`",
+                                        snippet(cx, push_args[0].span, "_"),
+                                        snippet(cx, push_args[1].span, "_"),
+                                        snippet(cx, end.span, "_")));
+        }}
+    }
+}
+
+/// Is `expr` a literal or a variable other than the loop variable bound by `pat`?
+fn is_loop_invariant(pat: &Pat, expr: &Expr) -> bool {
+    match expr.node {
+        ExprLit(_) => true,
+        ExprPath(None, ref path) if path.segments.len() == 1 => {
+            match pat.node {
+                PatKind::Ident(_, ref ident, _) => ident.node.name != path.segments[0].identifier.name,
+                _ => true,
+            }
+        }
+        _ => false,
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct SplitCollectIndex;
+
+impl LintPass for SplitCollectIndex {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(SPLIT_COLLECT_INDEX)
+    }
+}
+
+impl LateLintPass for SplitCollectIndex {
+    fn check_expr(&mut self, cx: &LateContext, expr: &Expr) {
+        if_let_chain! {[
+            let ExprIndex(ref collect, ref index) = expr.node,
+            let Some(arglists) = method_chain_args(collect, &["split", "collect"]),
+            walk_ptrs_ty(cx.tcx.expr_ty(&arglists[0][0])).sty == ty::TyStr || is_string(cx, &arglists[0][0]),
+            match_type(cx, cx.tcx.expr_ty(collect), &VEC_PATH),
+            let Some((Constant::Int(n, _, _), _)) = constant(cx, index),
+            !in_macro(cx, expr.span)
+        ], {
+            let split = snippet(cx, arglists[0][0].span, "_");
+            let pat = snippet(cx, arglists[0][1].span, "_");
+            let sugg = if n == 0 {
+                format!("{}.split({}).next().unwrap()", split, pat)
+            } else {
+                format!("{}.split({}).nth({}).unwrap()", split, pat, n)
+            };
+            span_lint_and_then(cx,
+                               SPLIT_COLLECT_INDEX,
+                               expr.span,
+                               "collecting the parts of a `split` into a `Vec` only to index it",
+                               |db| {
+                                   db.span_suggestion(expr.span, "try", sugg);
+                               });
+        }}
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct ManualStringFold;
+
+impl LintPass for ManualStringFold {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(MANUAL_STRING_FOLD)
+    }
+}
+
+impl LateLintPass for ManualStringFold {
+    fn check_expr(&mut self, cx: &LateContext, expr: &Expr) {
+        if_let_chain! {[
+            let ExprMethodCall(ref name, _, ref args) = expr.node,
+            name.node.as_str() == "fold",
+            args.len() == 3,
+            match_trait_method(cx, expr, &["core", "iter", "Iterator"]),
+            let ExprCall(ref fun, ref new_args) = args[1].node,
+            new_args.is_empty(),
+            let ExprPath(None, ref new_path) = fun.node,
+            match_path(new_path, &["String", "new"]),
+            let ExprClosure(_, ref decl, ref body) = args[2].node,
+            decl.inputs.len() == 2,
+            let PatKind::Ident(_, ref acc, None) = decl.inputs[0].pat.node,
+            let PatKind::Ident(_, ref elem, None) = decl.inputs[1].pat.node,
+            body.stmts.len() == 1,
+            let Some(ref ret) = body.expr,
+            is_path_to(ret, acc.node),
+            let StmtSemi(ref push, _) = body.stmts[0].node,
+            pushes_onto(push, acc.node, elem.node),
+            // `String` only implements `FromIterator` for these
+            is_collectable_into_string(cx, cx.tcx.pat_ty(&decl.inputs[1].pat))
+        ], {
+            span_help_and_lint(cx,
+                               MANUAL_STRING_FOLD,
+                               expr.span,
+                               "this `fold` only pushes every element onto a `String`",
+                               &format!("consider using `{}.collect::<String>()`", snippet(cx, args[0].span, "_")));
+        }}
+    }
+}
+
+fn is_collectable_into_string(cx: &LateContext, ty: ty::Ty) -> bool {
+    match ty.sty {
+        ty::TyChar => true,
+        ty::TyRef(_, ty::TypeAndMut { ty, .. }) => ty.sty == ty::TyStr || ty.sty == ty::TyChar,
+        _ => match_type(cx, ty, &STRING_PATH),
+    }
+}
+
+/// Is `expr` `acc.push_str(elem)` or `acc.push(elem)`?
+fn pushes_onto(expr: &Expr, acc: Ident, elem: Ident) -> bool {
+    if let ExprMethodCall(ref name, _, ref args) = expr.node {
+        (name.node.as_str() == "push_str" || name.node.as_str() == "push") && args.len() == 2 &&
+        is_path_to(&args[0], acc) && is_path_to(&args[1], elem)
+    } else {
+        false
+    }
+}
+
+fn is_path_to(expr: &Expr, ident: Ident) -> bool {
+    if let ExprPath(None, ref path) = expr.node {
+        !path.global && path.segments.len() == 1 && path.segments[0].identifier.name == ident.name
+    } else {
+        false
+    }
+}
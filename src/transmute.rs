@@ -1,6 +1,9 @@
 use rustc::lint::*;
+use rustc::middle::ty::TypeVariants::{TyBool, TyUint};
 use rustc_front::hir::*;
+use syntax::ast::UintTy;
 use utils;
+use utils::{snippet, span_lint_and_then};
 
 /// **What it does:** This lint checks for transmutes to the original type of the object.
 ///
@@ -15,11 +18,25 @@ declare_lint! {
     "transmutes that have the same to and from types"
 }
 
+/// **What it does:** This lint checks for transmutes from a `u8` to a `bool`.
+///
+/// **Why is this bad?** Any value other than `0` or `1` gives an invalid `bool`, which is undefined
+/// behaviour. Comparing to `0` is safe and does what was meant.
+///
+/// **Known problems:** None.
+///
+/// **Example:** `let x: bool = std::mem::transmute(1_u8);` could be `let x = 1_u8 != 0;`
+declare_lint! {
+    pub TRANSMUTE_INT_TO_BOOL,
+    Warn,
+    "transmutes from a `u8` to a `bool`, which is unsound for values other than 0 and 1"
+}
+
 pub struct UselessTransmute;
 
 impl LintPass for UselessTransmute {
     fn get_lints(&self) -> LintArray {
-        lint_array!(USELESS_TRANSMUTE)
+        lint_array!(USELESS_TRANSMUTE, TRANSMUTE_INT_TO_BOOL)
     }
 }
 
@@ -37,6 +54,15 @@ impl LateLintPass for UselessTransmute {
                         cx.span_lint(USELESS_TRANSMUTE,
                                      e.span,
                                      &format!("transmute from a type (`{}`) to itself", from_ty));
+                    } else if let (&TyUint(UintTy::U8), &TyBool) = (&from_ty.sty, &to_ty.sty) {
+                        let sugg = format!("{} != 0", snippet(cx, args[0].span, ".."));
+                        span_lint_and_then(cx,
+                                           TRANSMUTE_INT_TO_BOOL,
+                                           e.span,
+                                           "transmute from a `u8` to a `bool`",
+                                           |db| {
+                                               db.span_suggestion(e.span, "consider comparing to zero instead", sugg);
+                                           });
                     }
                 }
             }
@@ -1,11 +1,12 @@
 use reexport::*;
+use rustc::front::map::NodeItem;
 use rustc::lint::*;
 use rustc::middle::const_eval;
 use rustc::middle::ty;
 use rustc_front::hir::*;
-use rustc_front::intravisit::{FnKind, Visitor, walk_ty};
+use rustc_front::intravisit::{FnKind, Visitor, walk_expr, walk_ty};
 use rustc_front::util::{is_comparison_binop, binop_to_string};
-use syntax::ast::{IntTy, UintTy, FloatTy};
+use syntax::ast::{Attribute, IntTy, UintTy, FloatTy};
 use syntax::codemap::Span;
 use utils::*;
 
@@ -216,6 +217,49 @@ declare_lint! {
     "casts that may cause wrapping around the value, e.g `x as i32` where `x: u32` and `x > i32::MAX`"
 }
 
+/// **What it does:** This lint checks for casts between integer types which can never lose
+/// information, e.g. `x as u64` where `x: u32`.
+///
+/// **Why is this bad?** Nothing, but the `From` conversion documents that the conversion is
+/// lossless, and keeps it so if the types change later, where `as` would silently truncate.
+///
+/// **Known problems:** None
+///
+/// **Example:** `fn as_u64(x: u8) -> u64 { x as u64 }` could be `fn as_u64(x: u8) -> u64 { u64::from(x) }`
+declare_lint! {
+    pub CAST_LOSSLESS, Allow,
+    "casts which are always lossless, e.g `x as u64` where `x: u8`; suggests `u64::from(x)` instead"
+}
+
+/// **What it does:** This lint checks for casts to a narrower unsigned integer type which may
+/// truncate the value, e.g. `x as u8` where `x: u32`, without making the truncation explicit.
+///
+/// **Why is this bad?** It is not clear whether the truncation is intended or the value is
+/// believed to always fit. A mask like `(x & 0xff) as u8` documents the former, and a checked
+/// conversion should be used for the latter.
+///
+/// **Known problems:** There is no checked conversion in the standard library yet.
+///
+/// **Example:** `fn as_u8(x: u32) -> u8 { x as u8 }`
+declare_lint! {
+    pub CAST_POSSIBLE_TRUNCATION_TRYFROM, Allow,
+    "casts to narrower unsigned types which silently truncate, e.g `x as u8` where `x: u32`"
+}
+
+/// **What it does:** This lint checks for casts of the result of a `.len()` call to an integer
+/// type of 32 bits or less, e.g. `v.len() as u16`.
+///
+/// **Why is this bad?** The length of a large collection silently gets truncated or wraps around.
+///
+/// **Known problems:** There is no checked conversion in the standard library yet, the length
+/// has to be compared to the maximum of the target type by hand.
+///
+/// **Example:** `let n = v.len() as u16;`
+declare_lint! {
+    pub CAST_LEN_TRUNCATION, Warn,
+    "casting a `.len()` to a narrow integer type, e.g `v.len() as u16`, which may truncate it"
+}
+
 /// Returns the size in bits of an integral type.
 /// Will return 0 if the type is not an int or uint variant
 fn int_ty_to_nbits(typ: &ty::TyS) -> usize {
@@ -280,7 +324,7 @@ enum ArchSuffix {
     None,
 }
 
-fn check_truncation_and_wrapping(cx: &LateContext, expr: &Expr, cast_from: &ty::TyS, cast_to: &ty::TyS) {
+fn check_truncation_and_wrapping(cx: &LateContext, expr: &Expr, op: &Expr, cast_from: &ty::TyS, cast_to: &ty::TyS) {
     let arch_64_suffix = " on targets with 64-bit wide pointers";
     let arch_32_suffix = " on targets with 32-bit wide pointers";
     let cast_unsigned_to_signed = !cast_from.is_signed() && cast_to.is_signed();
@@ -326,6 +370,16 @@ fn check_truncation_and_wrapping(cx: &LateContext, expr: &Expr, cast_from: &ty::
                                ArchSuffix::_64 => arch_64_suffix,
                                ArchSuffix::None => "",
                            }));
+        if !cast_to.is_signed() && !is_isize_or_usize(cast_to) {
+            span_help_and_lint(cx,
+                               CAST_POSSIBLE_TRUNCATION_TRYFROM,
+                               expr.span,
+                               &format!("casting {} to {} silently truncates the value", cast_from, cast_to),
+                               &format!("if the truncation is intended, make it explicit: `({} & 0x{:x}) as {}`",
+                                        snippet(cx, op.span, ".."),
+                                        (1u64 << to_nbits) - 1,
+                                        cast_to));
+        }
     }
     if span_wrap {
         span_lint(cx,
@@ -347,10 +401,55 @@ impl LintPass for CastPass {
         lint_array!(CAST_PRECISION_LOSS,
                     CAST_SIGN_LOSS,
                     CAST_POSSIBLE_TRUNCATION,
-                    CAST_POSSIBLE_WRAP)
+                    CAST_POSSIBLE_WRAP,
+                    CAST_LOSSLESS,
+                    CAST_POSSIBLE_TRUNCATION_TRYFROM,
+                    CAST_LEN_TRUNCATION)
+    }
+}
+
+fn check_lossless(cx: &LateContext, expr: &Expr, op: &Expr, cast_from: &ty::TyS, cast_to: &ty::TyS) {
+    // the width of `isize` and `usize` is platform dependent, and so is the existence of `From`
+    // implementations for them
+    if is_isize_or_usize(cast_from) || is_isize_or_usize(cast_to) {
+        return;
+    }
+    // a signed type never fits in an unsigned one
+    if cast_from.is_signed() && !cast_to.is_signed() {
+        return;
+    }
+    if int_ty_to_nbits(cast_from) < int_ty_to_nbits(cast_to) {
+        span_lint_and_then(cx,
+                           CAST_LOSSLESS,
+                           expr.span,
+                           &format!("casting {} to {} may become silently lossy if types change", cast_from, cast_to),
+                           |db| {
+                               db.span_suggestion(expr.span,
+                                                  "use a `From` conversion instead",
+                                                  format!("{}::from({})", cast_to, snippet(cx, op.span, "..")));
+                           });
     }
 }
 
+fn check_len_truncation(cx: &LateContext, expr: &Expr, op: &Expr, cast_from: &ty::TyS, cast_to: &ty::TyS) {
+    if_let_chain! {[
+        let ExprMethodCall(ref name, _, ref args) = op.node,
+        name.node.as_str() == "len",
+        args.len() == 1,
+        let ty::TyUint(UintTy::Us) = cast_from.sty,
+        !is_isize_or_usize(cast_to),
+        int_ty_to_nbits(cast_to) <= 32
+    ], {
+        span_help_and_lint(cx,
+                           CAST_LEN_TRUNCATION,
+                           expr.span,
+                           &format!("casting the length of a collection to {} may truncate it", cast_to),
+                           &format!("check that `{}` is at most `{}::MAX` before casting, or use a wider type",
+                                    snippet(cx, op.span, ".."),
+                                    cast_to));
+    }}
+}
+
 impl LateLintPass for CastPass {
     fn check_expr(&mut self, cx: &LateContext, expr: &Expr) {
         if let ExprCast(ref ex, _) = expr.node {
@@ -387,7 +486,9 @@ impl LateLintPass for CastPass {
                                       expr.span,
                                       &format!("casting {} to {} may lose the sign of the value", cast_from, cast_to));
                         }
-                        check_truncation_and_wrapping(cx, expr, cast_from, cast_to);
+                        check_truncation_and_wrapping(cx, expr, ex, cast_from, cast_to);
+                        check_lossless(cx, expr, ex, cast_from, cast_to);
+                        check_len_truncation(cx, expr, ex, cast_from, cast_to);
                     }
                     (false, false) => {
                         if let (&ty::TyFloat(FloatTy::F64), &ty::TyFloat(FloatTy::F32)) = (&cast_from.sty,
@@ -538,6 +639,591 @@ impl<'v> Visitor<'v> for TypeComplexityVisitor {
     }
 }
 
+/// **What it does:** This lint checks for tuple types with more elements than a certain threshold (4 by default, can be changed with the `#[large_tuple = "N"]` attribute) in function signatures and `type` definitions.
+///
+/// **Why is this bad?** The elements of large tuples can only be told apart by their position, which makes code using them hard to read. A struct with named fields documents itself.
+///
+/// **Known problems:** None
+///
+/// **Example:** `fn draw(p: (u32, u32, u8, u8, u8)) { .. }`
+declare_lint! {
+    pub LARGE_TUPLE, Allow,
+    "tuple types with many elements; recommends using a named struct instead"
+}
+
+pub struct LargeTuplePass {
+    limit: LimitStack,
+}
+
+impl LargeTuplePass {
+    pub fn new(limit: u64) -> Self {
+        LargeTuplePass { limit: LimitStack::new(limit) }
+    }
+
+    fn check_fndecl(&self, cx: &LateContext, decl: &FnDecl) {
+        for arg in &decl.inputs {
+            self.check_type(cx, &arg.ty);
+        }
+        if let Return(ref ty) = decl.output {
+            self.check_type(cx, ty);
+        }
+    }
+
+    fn check_type(&self, cx: &LateContext, ty: &Ty) {
+        if in_macro(cx, ty.span) {
+            return;
+        }
+        let mut visitor = LargeTupleVisitor {
+            cx: cx,
+            limit: self.limit.limit(),
+        };
+        visitor.visit_ty(ty);
+    }
+}
+
+impl LintPass for LargeTuplePass {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(LARGE_TUPLE)
+    }
+}
+
+impl LateLintPass for LargeTuplePass {
+    fn check_fn(&mut self, cx: &LateContext, _: FnKind, decl: &FnDecl, _: &Block, _: Span, _: NodeId) {
+        self.check_fndecl(cx, decl);
+    }
+
+    fn check_item(&mut self, cx: &LateContext, item: &Item) {
+        if let ItemTy(ref ty, _) = item.node {
+            self.check_type(cx, ty);
+        }
+    }
+
+    fn check_trait_item(&mut self, cx: &LateContext, item: &TraitItem) {
+        // methods with default impl are covered by check_fn
+        if let MethodTraitItem(MethodSig { ref decl, .. }, None) = item.node {
+            self.check_fndecl(cx, decl);
+        }
+    }
+
+    fn enter_lint_attrs(&mut self, cx: &LateContext, attrs: &[Attribute]) {
+        self.limit.push_attrs(cx.sess(), attrs, "large_tuple");
+    }
+    fn exit_lint_attrs(&mut self, cx: &LateContext, attrs: &[Attribute]) {
+        self.limit.pop_attrs(cx.sess(), attrs, "large_tuple");
+    }
+}
+
+/// Walks a type and reports every tuple type with more than `limit` elements.
+struct LargeTupleVisitor<'a, 'tcx: 'a> {
+    cx: &'a LateContext<'a, 'tcx>,
+    limit: u64,
+}
+
+impl<'a, 'tcx: 'a, 'v> Visitor<'v> for LargeTupleVisitor<'a, 'tcx> {
+    fn visit_ty(&mut self, ty: &'v Ty) {
+        if let TyTup(ref elems) = ty.node {
+            if elems.len() as u64 > self.limit {
+                span_note_and_lint(self.cx,
+                                   LARGE_TUPLE,
+                                   ty.span,
+                                   &format!("this tuple type has {} elements", elems.len()),
+                                   ty.span,
+                                   "consider using a struct with named fields instead");
+            }
+        }
+        walk_ty(self, ty);
+    }
+}
+
+/// **What it does:** This lint checks for function signatures using `&mut &T` or `&&mut T`.
+///
+/// **Why is this bad?** A `&mut &T` only allows to change which `T` the inner reference points to, not the `T` itself, and a `&&mut T` does not allow to mutate the `T` at all. Both are rarely what was intended.
+///
+/// **Known problems:** `&mut &[u8]` is a legitimate way to pass a `Read`er which advances through a slice.
+///
+/// **Example:** `fn update(x: &mut &u32) { .. }`
+declare_lint! {
+    pub MIXED_REF_NESTING, Allow,
+    "function signatures with `&mut &T` or `&&mut T`, which are rarely what was intended"
+}
+
+#[derive(Copy, Clone)]
+pub struct MixedRefNesting;
+
+impl LintPass for MixedRefNesting {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(MIXED_REF_NESTING)
+    }
+}
+
+impl LateLintPass for MixedRefNesting {
+    fn check_fn(&mut self, cx: &LateContext, _: FnKind, decl: &FnDecl, _: &Block, _: Span, _: NodeId) {
+        check_ref_nesting_fndecl(cx, decl);
+    }
+
+    fn check_trait_item(&mut self, cx: &LateContext, item: &TraitItem) {
+        // methods with default impl are covered by check_fn
+        if let MethodTraitItem(MethodSig { ref decl, .. }, None) = item.node {
+            check_ref_nesting_fndecl(cx, decl);
+        }
+    }
+}
+
+fn check_ref_nesting_fndecl(cx: &LateContext, decl: &FnDecl) {
+    let mut visitor = RefNestingVisitor { cx: cx };
+    for arg in &decl.inputs {
+        visitor.visit_ty(&arg.ty);
+    }
+    if let Return(ref ty) = decl.output {
+        visitor.visit_ty(ty);
+    }
+}
+
+/// Walks a type and reports every reference to a reference of the other mutability.
+struct RefNestingVisitor<'a, 'tcx: 'a> {
+    cx: &'a LateContext<'a, 'tcx>,
+}
+
+impl<'a, 'tcx: 'a, 'v> Visitor<'v> for RefNestingVisitor<'a, 'tcx> {
+    fn visit_ty(&mut self, ty: &'v Ty) {
+        if_let_chain! {[
+            !in_macro(self.cx, ty.span),
+            let TyRptr(_, MutTy { ty: ref inner, mutbl: outer_mut }) = ty.node,
+            let TyRptr(_, MutTy { mutbl: inner_mut, .. }) = inner.node,
+            outer_mut != inner_mut
+        ], {
+            let note = if outer_mut == MutMutable {
+                "this only allows to change which value the inner reference points to, not the value itself"
+            } else {
+                "the value behind the inner `&mut` cannot be mutated through the outer shared reference"
+            };
+            span_note_and_lint(self.cx,
+                               MIXED_REF_NESTING,
+                               ty.span,
+                               "reference to a reference of a different mutability",
+                               ty.span,
+                               note);
+        }}
+        walk_ty(self, ty);
+    }
+}
+
+/// **What it does:** This lint checks for functions returning `&'static mut T`.
+///
+/// **Why is this bad?** A mutable reference must be unique. A function which can be called several times and returns a `&'static mut` to the same value allows aliasing mutable references, which is undefined behaviour; this usually comes with an `unsafe` block hiding the problem.
+///
+/// **Known problems:** Functions which leak a fresh allocation on each call are fine, the lint should be allowed on them with a comment explaining why.
+///
+/// **Example:** `fn config() -> &'static mut Config { .. }`
+declare_lint! {
+    pub STATIC_MUT_REF_RETURN, Warn,
+    "functions returning `&'static mut T`, which may hand out aliasing mutable references"
+}
+
+#[derive(Copy, Clone)]
+pub struct StaticMutRefReturn;
+
+impl LintPass for StaticMutRefReturn {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(STATIC_MUT_REF_RETURN)
+    }
+}
+
+impl LateLintPass for StaticMutRefReturn {
+    fn check_fn(&mut self, cx: &LateContext, _: FnKind, decl: &FnDecl, _: &Block, _: Span, _: NodeId) {
+        check_static_mut_ref_return(cx, decl);
+    }
+
+    fn check_trait_item(&mut self, cx: &LateContext, item: &TraitItem) {
+        // methods with default impl are covered by check_fn
+        if let MethodTraitItem(MethodSig { ref decl, .. }, None) = item.node {
+            check_static_mut_ref_return(cx, decl);
+        }
+    }
+}
+
+fn check_static_mut_ref_return(cx: &LateContext, decl: &FnDecl) {
+    if_let_chain! {[
+        let Return(ref ty) = decl.output,
+        !in_macro(cx, ty.span),
+        let TyRptr(Some(ref lifetime), MutTy { mutbl: MutMutable, .. }) = ty.node,
+        lifetime.name.as_str() == "'static"
+    ], {
+        span_note_and_lint(cx,
+                           STATIC_MUT_REF_RETURN,
+                           ty.span,
+                           "this function returns a `&'static mut` reference",
+                           ty.span,
+                           "if the function can be called more than once, the returned references may alias, \
+                            which is undefined behaviour. If each call leaks a new value, allow this lint and \
+                            document it");
+    }}
+}
+
+/// **What it does:** This lint checks for functions with more `bool` parameters than a certain threshold (2 by default, can be changed with the `#[many_bool_params = "N"]` attribute).
+///
+/// **Why is this bad?** Call sites like `draw(true, false, true)` cannot be understood without looking up the signature, and it is easy to swap two flags. An options struct or a few two-variant enums make the call self-documenting.
+///
+/// **Known problems:** None
+///
+/// **Example:** `fn draw(filled: bool, shadow: bool, antialias: bool) { .. }`
+declare_lint! {
+    pub MANY_BOOL_PARAMS, Allow,
+    "functions with many `bool` parameters; recommends using an options struct or enums instead"
+}
+
+pub struct ManyBoolParams {
+    limit: LimitStack,
+}
+
+impl ManyBoolParams {
+    pub fn new(limit: u64) -> Self {
+        ManyBoolParams { limit: LimitStack::new(limit) }
+    }
+
+    fn check_fndecl(&self, cx: &LateContext, decl: &FnDecl) {
+        let bools: Vec<_> = decl.inputs.iter().filter(|arg| is_bool_ty(&arg.ty)).collect();
+        if bools.len() as u64 > self.limit.limit() {
+            let span = Span { hi: bools[bools.len() - 1].ty.span.hi, ..bools[0].pat.span };
+            if in_macro(cx, span) {
+                return;
+            }
+            span_note_and_lint(cx,
+                               MANY_BOOL_PARAMS,
+                               span,
+                               &format!("this function has {} `bool` parameters", bools.len()),
+                               span,
+                               "consider using an options struct or enums with descriptive variants instead");
+        }
+    }
+}
+
+impl LintPass for ManyBoolParams {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(MANY_BOOL_PARAMS)
+    }
+}
+
+impl LateLintPass for ManyBoolParams {
+    fn check_fn(&mut self, cx: &LateContext, _: FnKind, decl: &FnDecl, _: &Block, _: Span, _: NodeId) {
+        self.check_fndecl(cx, decl);
+    }
+
+    fn check_trait_item(&mut self, cx: &LateContext, item: &TraitItem) {
+        // methods with default impl are covered by check_fn
+        if let MethodTraitItem(MethodSig { ref decl, .. }, None) = item.node {
+            self.check_fndecl(cx, decl);
+        }
+    }
+
+    fn enter_lint_attrs(&mut self, cx: &LateContext, attrs: &[Attribute]) {
+        self.limit.push_attrs(cx.sess(), attrs, "many_bool_params");
+    }
+    fn exit_lint_attrs(&mut self, cx: &LateContext, attrs: &[Attribute]) {
+        self.limit.pop_attrs(cx.sess(), attrs, "many_bool_params");
+    }
+}
+
+fn is_bool_ty(ty: &Ty) -> bool {
+    if let TyPath(None, ref path) = ty.node {
+        path.segments.len() == 1 && match_path(path, &["bool"])
+    } else {
+        false
+    }
+}
+
+/// **What it does:** This lint checks for trait bounds which appear twice in the same list of bounds, like `T: Clone
+/// + Clone`, and for `Clone` bounds next to a `Copy` bound.
+///
+/// **Why is this bad?** The redundant bound adds nothing, `Copy` already requires `Clone`.
+///
+/// **Known problems:** Only bounds without generic parameters are compared.
+///
+/// **Example:** `fn dup<T: Copy + Clone>(x: T) -> (T, T) { .. }`
+declare_lint! {
+    pub REDUNDANT_TRAIT_BOUND, Warn,
+    "a trait bound which appears twice, or a `Clone` bound next to a `Copy` bound"
+}
+
+#[derive(Copy, Clone)]
+pub struct RedundantTraitBound;
+
+impl LintPass for RedundantTraitBound {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(REDUNDANT_TRAIT_BOUND)
+    }
+}
+
+impl LateLintPass for RedundantTraitBound {
+    fn check_generics(&mut self, cx: &LateContext, generics: &Generics) {
+        for param in generics.ty_params.iter() {
+            check_redundant_bounds(cx, &param.bounds);
+        }
+        for predicate in &generics.where_clause.predicates {
+            if let WherePredicate::BoundPredicate(ref pred) = *predicate {
+                check_redundant_bounds(cx, &pred.bounds);
+            }
+        }
+    }
+}
+
+fn check_redundant_bounds(cx: &LateContext, bounds: &[TyParamBound]) {
+    if bounds.len() < 2 || in_macro(cx, bound_span(&bounds[0])) {
+        return;
+    }
+
+    let trait_def = |bound: &TyParamBound| {
+        if let TraitTyParamBound(ref poly, TraitBoundModifier::None) = *bound {
+            cx.tcx.def_map.borrow().get(&poly.trait_ref.ref_id).map(|def| (def.def_id(), poly))
+        } else {
+            None
+        }
+    };
+    let has_copy = bounds.iter()
+                         .filter_map(|bound| trait_def(bound))
+                         .any(|(def_id, _)| match_def_path(cx, def_id, &["core", "marker", "Copy"]));
+
+    let mut redundant = vec![];
+    for (i, bound) in bounds.iter().enumerate() {
+        if let Some((def_id, poly)) = trait_def(bound) {
+            let duplicate = has_no_parameters(poly) &&
+                            bounds[..i].iter()
+                                       .filter_map(|bound| trait_def(bound))
+                                       .any(|(other_id, other)| other_id == def_id && has_no_parameters(other));
+            if duplicate || (has_copy && match_def_path(cx, def_id, &["core", "clone", "Clone"])) {
+                redundant.push(i);
+            }
+        }
+    }
+    if redundant.is_empty() {
+        return;
+    }
+
+    let span = Span { hi: bound_span(&bounds[bounds.len() - 1]).hi, ..bound_span(&bounds[0]) };
+    let maybe = bounds.iter().any(|bound| {
+        if let TraitTyParamBound(_, TraitBoundModifier::Maybe) = *bound {
+            true
+        } else {
+            false
+        }
+    });
+    span_lint_and_then(cx, REDUNDANT_TRAIT_BOUND, span, "redundant trait bounds", |db| {
+        // the span of `?Sized` does not include the `?`
+        if !maybe {
+            let kept = bounds.iter()
+                             .enumerate()
+                             .filter(|&(i, _)| !redundant.contains(&i))
+                             .map(|(_, bound)| snippet(cx, bound_span(bound), ".."))
+                             .collect::<Vec<_>>();
+            db.span_suggestion(span, "remove the redundant bounds", kept.join(" + "));
+        }
+    });
+}
+
+fn has_no_parameters(poly: &PolyTraitRef) -> bool {
+    poly.trait_ref.path.segments.last().map_or(true, |segment| segment.parameters.is_empty())
+}
+
+fn bound_span(bound: &TyParamBound) -> Span {
+    match *bound {
+        TraitTyParamBound(ref poly, _) => poly.span,
+        RegionTyParamBound(ref lifetime) => lifetime.span,
+    }
+}
+
+/// **What it does:** This lint checks for functions returning an `Option` which always return `Some(..)`.
+///
+/// **Why is this bad?** The `Option` tells the caller to expect `None`, and makes it handle a case which never
+/// happens. Returning the value directly is simpler.
+///
+/// **Known problems:** The signature may be needed as is, e.g. to pass the function where a `fn(..) -> Option<T>`
+/// is expected, or to allow returning `None` in the future, hence this lint is `Allow` by default.
+///
+/// **Example:** `fn get(&self) -> Option<u32> { Some(self.x) }`
+declare_lint! {
+    pub ALWAYS_SOME_RETURN, Allow,
+    "functions returning an `Option` which always return `Some(..)`"
+}
+
+#[derive(Copy, Clone)]
+pub struct AlwaysSomeReturn;
+
+impl LintPass for AlwaysSomeReturn {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(ALWAYS_SOME_RETURN)
+    }
+}
+
+impl LateLintPass for AlwaysSomeReturn {
+    fn check_fn(&mut self, cx: &LateContext, kind: FnKind, decl: &FnDecl, body: &Block, span: Span, id: NodeId) {
+        match kind {
+            FnKind::ItemFn(..) => (),
+            FnKind::Method(..) => {
+                if let Some(NodeItem(it)) = cx.tcx.map.find(cx.tcx.map.get_parent(id)) {
+                    if let ItemImpl(_, _, _, Some(_), _, _) = it.node {
+                        return; // ignore trait impls, the signature is not ours to change
+                    }
+                }
+            }
+            FnKind::Closure => return,
+        }
+
+        if_let_chain! {[
+            let Return(ref ret_ty) = decl.output,
+            let Some(ref tail) = body.expr,
+            !in_macro(cx, span),
+            match_type(cx, cx.tcx.expr_ty(tail), &OPTION_PATH),
+            always_some(tail)
+        ], {
+            let mut visitor = ReturnVisitor { always_some: true };
+            for stmt in &body.stmts {
+                visitor.visit_stmt(stmt);
+            }
+            visitor.visit_expr(tail);
+            if visitor.always_some {
+                span_note_and_lint(cx,
+                                   ALWAYS_SOME_RETURN,
+                                   ret_ty.span,
+                                   "this function always returns `Some(..)`",
+                                   ret_ty.span,
+                                   "consider returning the value without the `Option`");
+            }
+        }}
+    }
+}
+
+/// Is the value of this expression always `Some(..)`? `return`s are checked by `ReturnVisitor`.
+fn always_some(expr: &Expr) -> bool {
+    match expr.node {
+        ExprCall(ref fun, ref args) => {
+            if let ExprPath(None, ref path) = fun.node {
+                args.len() == 1 && match_path(path, &["Some"])
+            } else {
+                false
+            }
+        }
+        ExprBlock(ref block) => block_always_some(block),
+        ExprIf(_, ref then, Some(ref els)) => block_always_some(then) && always_some(els),
+        ExprMatch(_, ref arms, _) => arms.iter().all(|arm| always_some(&arm.body)),
+        ExprRet(_) => true,
+        _ => false,
+    }
+}
+
+fn block_always_some(block: &Block) -> bool {
+    block.expr.as_ref().map_or(false, |expr| always_some(expr))
+}
+
+/// Check that all `return`s of a function body return `Some(..)`.
+struct ReturnVisitor {
+    always_some: bool,
+}
+
+impl<'v> Visitor<'v> for ReturnVisitor {
+    fn visit_expr(&mut self, expr: &'v Expr) {
+        match expr.node {
+            ExprRet(Some(ref value)) if !always_some(value) => self.always_some = false,
+            // `return`s in closures do not return from the function
+            ExprClosure(..) => (),
+            _ => walk_expr(self, expr),
+        }
+    }
+}
+
+/// **What it does:** This lint checks for type parameters of functions which have no bounds and
+/// are used only once in the arguments and nowhere else, e.g. `fn foo<T>(x: T)`.
+///
+/// **Why is this bad?** Such a parameter can hardly be used for anything, it is likely that a
+/// concrete type was meant.
+///
+/// **Known problems:** Functions like `std::mem::drop` are generic on purpose.
+///
+/// **Example:** `fn consume<T>(x: T) { }`
+declare_lint! {
+    pub SINGLE_USE_TYPE_PARAM, Allow,
+    "type parameters of functions without bounds which are used only once"
+}
+
+#[derive(Copy, Clone)]
+pub struct SingleUseTypeParam;
+
+impl LintPass for SingleUseTypeParam {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(SINGLE_USE_TYPE_PARAM)
+    }
+}
+
+impl LateLintPass for SingleUseTypeParam {
+    fn check_item(&mut self, cx: &LateContext, item: &Item) {
+        if let ItemFn(ref decl, _, _, _, ref generics, ref body) = item.node {
+            if in_macro(cx, item.span) {
+                return;
+            }
+
+            let unbounded = generics.ty_params.iter().filter(|param| {
+                param.bounds.is_empty() && param.default.is_none() && !in_where_clause(generics, param.name)
+            });
+
+            for param in unbounded {
+                let mut inputs = TyParamUseVisitor { name: param.name, count: 0 };
+                for input in &decl.inputs {
+                    inputs.visit_ty(&input.ty);
+                }
+
+                // a parameter appearing in the return type, as in `fn make<T>() -> T`, is chosen by
+                // the caller
+                let mut others = TyParamUseVisitor { name: param.name, count: 0 };
+                if let Return(ref ty) = decl.output {
+                    others.visit_ty(ty);
+                }
+                others.visit_block(body);
+
+                if inputs.count == 1 && others.count == 0 {
+                    span_note_and_lint(cx,
+                                       SINGLE_USE_TYPE_PARAM,
+                                       param.span,
+                                       &format!("type parameter `{}` has no bounds and is used only once", param.name),
+                                       param.span,
+                                       "consider using a concrete type instead");
+                }
+            }
+        }
+    }
+}
+
+fn in_where_clause(generics: &Generics, name: Name) -> bool {
+    generics.where_clause.predicates.iter().any(|predicate| {
+        if let WherePredicate::BoundPredicate(ref pred) = *predicate {
+            is_ty_param(&pred.bounded_ty, name)
+        } else {
+            false
+        }
+    })
+}
+
+fn is_ty_param(ty: &Ty, name: Name) -> bool {
+    if let TyPath(None, ref path) = ty.node {
+        path.segments.len() == 1 && path.segments[0].identifier.name == name
+    } else {
+        false
+    }
+}
+
+/// Count the uses of a type parameter.
+struct TyParamUseVisitor {
+    name: Name,
+    count: usize,
+}
+
+impl<'v> Visitor<'v> for TyParamUseVisitor {
+    fn visit_ty(&mut self, ty: &'v Ty) {
+        if is_ty_param(ty, self.name) {
+            self.count += 1;
+        }
+        walk_ty(self, ty);
+    }
+}
+
 /// **What it does:** This lint points out expressions where a character literal is casted to `u8` and suggests using a byte literal instead.
 ///
 /// **Why is this bad?** In general, casting values to smaller types is error-prone and should be avoided where possible. In the particular case of converting a character literal to u8, it is easy to avoid by just using a byte literal instead. As an added bonus, `b'a'` is even slightly shorter than `'a' as u8`.
@@ -659,6 +1345,7 @@ fn detect_absurd_comparison<'a>(cx: &LateContext, op: BinOp_, lhs: &'a Expr, rhs
     })
 }
 
+#[allow(cast_lossless)]
 fn detect_extreme_expr<'a>(cx: &LateContext, expr: &'a Expr) -> Option<ExtremeExpr<'a>> {
     use rustc::middle::const_eval::EvalHint::ExprTypeChecked;
     use types::ExtremeType::*;
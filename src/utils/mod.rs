@@ -20,18 +20,22 @@ pub type MethodArgs = HirVec<P<Expr>>;
 
 // module DefPaths for certain structs/enums we check for
 pub const BEGIN_UNWIND: [&'static str; 3] = ["std", "rt", "begin_unwind"];
+pub const BEGIN_UNWIND_FMT: [&'static str; 3] = ["std", "rt", "begin_unwind_fmt"];
 pub const BOX_NEW_PATH: [&'static str; 4] = ["std", "boxed", "Box", "new"];
 pub const BTREEMAP_ENTRY_PATH: [&'static str; 4] = ["collections", "btree", "map", "Entry"];
 pub const BTREEMAP_PATH: [&'static str; 4] = ["collections", "btree", "map", "BTreeMap"];
 pub const CLONE_PATH: [&'static str; 3] = ["clone", "Clone", "clone"];
 pub const CLONE_TRAIT_PATH: [&'static str; 2] = ["clone", "Clone"];
+pub const COMMAND_PATH: [&'static str; 3] = ["std", "process", "Command"];
 pub const COW_PATH: [&'static str; 3] = ["collections", "borrow", "Cow"];
 pub const DEBUG_FMT_METHOD_PATH: [&'static str; 4] = ["std", "fmt", "Debug", "fmt"];
 pub const DEFAULT_TRAIT_PATH: [&'static str; 3] = ["core", "default", "Default"];
 pub const DISPLAY_FMT_METHOD_PATH: [&'static str; 4] = ["std", "fmt", "Display", "fmt"];
+pub const DOUBLE_ENDED_ITERATOR_PATH: [&'static str; 3] = ["core", "iter", "DoubleEndedIterator"];
 pub const DROP_PATH: [&'static str; 3] = ["core", "mem", "drop"];
 pub const FMT_ARGUMENTS_NEWV1_PATH: [&'static str; 4] = ["std", "fmt", "Arguments", "new_v1"];
 pub const FMT_ARGUMENTV1_NEW_PATH: [&'static str; 4] = ["std", "fmt", "ArgumentV1", "new"];
+pub const FROM_TRAIT_PATH: [&'static str; 3] = ["core", "convert", "From"];
 pub const HASHMAP_ENTRY_PATH: [&'static str; 5] = ["std", "collections", "hash", "map", "Entry"];
 pub const HASHMAP_PATH: [&'static str; 5] = ["std", "collections", "hash", "map", "HashMap"];
 pub const HASH_PATH: [&'static str; 2] = ["hash", "Hash"];
@@ -40,6 +44,7 @@ pub const LL_PATH: [&'static str; 3] = ["collections", "linked_list", "LinkedLis
 pub const MUTEX_PATH: [&'static str; 4] = ["std", "sync", "mutex", "Mutex"];
 pub const OPEN_OPTIONS_PATH: [&'static str; 3] = ["std", "fs", "OpenOptions"];
 pub const OPTION_PATH: [&'static str; 3] = ["core", "option", "Option"];
+pub const ORDERING_PATH: [&'static str; 3] = ["core", "cmp", "Ordering"];
 pub const RANGE_FROM_PATH: [&'static str; 3] = ["std", "ops", "RangeFrom"];
 pub const RANGE_FULL_PATH: [&'static str; 3] = ["std", "ops", "RangeFull"];
 pub const RANGE_INCLUSIVE_NON_EMPTY_PATH: [&'static str; 4] = ["std", "ops", "RangeInclusive", "NonEmpty"];
@@ -316,6 +321,37 @@ pub fn get_item_name(cx: &LateContext, expr: &Expr) -> Option<Name> {
     }
 }
 
+/// Get the return type of the function the node `id` is in, if it is declared. Returns `None` inside closures,
+/// where a `return` would leave the closure instead.
+pub fn get_enclosing_fn_ret_ty<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, id: NodeId) -> Option<ty::Ty<'tcx>> {
+    let map = &cx.tcx.map;
+    let item = map.get_parent(id);
+    let mut id = id;
+
+    loop {
+        id = map.get_parent_node(id);
+        if id == item {
+            break;
+        }
+        if let Some(Node::NodeExpr(&Expr { node: ExprClosure(..), .. })) = map.find(id) {
+            return None;
+        }
+    }
+
+    let decl = match map.find(item) {
+        Some(Node::NodeItem(&Item { node: ItemFn(ref decl, ..), .. })) => decl,
+        Some(Node::NodeImplItem(&ImplItem { node: ImplItemKind::Method(ref sig, _), .. })) |
+        Some(Node::NodeTraitItem(&TraitItem { node: MethodTraitItem(ref sig, Some(_)), .. })) => &sig.decl,
+        _ => return None,
+    };
+
+    if let Return(ref ret) = decl.output {
+        cx.tcx.ast_ty_to_ty_cache.borrow().get(&ret.id).cloned()
+    } else {
+        None
+    }
+}
+
 /// Checks if a `let` decl is from a `for` loop desugaring.
 pub fn is_from_for_desugar(decl: &Decl) -> bool {
     if_let_chain! {
@@ -687,6 +723,48 @@ pub struct UnsugaredRange<'a> {
     pub limits: RangeLimits,
 }
 
+/// Return the only expression of a block expression, whether it is terminated by a `;` or not.
+pub fn get_single_expr(expr: &Expr) -> Option<&Expr> {
+    if let ExprBlock(ref block) = expr.node {
+        match (block.stmts.len(), &block.expr) {
+            (0, &Some(ref e)) => Some(e),
+            (1, &None) => {
+                match block.stmts[0].node {
+                    StmtExpr(ref e, _) | StmtSemi(ref e, _) => Some(e),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    } else {
+        None
+    }
+}
+
+/// Recover the essential nodes of a desugared for loop:
+/// `for pat in arg { body }` becomes `(pat, arg, body)`.
+pub fn recover_for_loop(expr: &Expr) -> Option<(&Pat, &Expr, &Expr)> {
+    if_let_chain! {
+        [
+            let ExprMatch(ref iterexpr, ref arms, _) = expr.node,
+            let ExprCall(_, ref iterargs) = iterexpr.node,
+            iterargs.len() == 1 && arms.len() == 1 && arms[0].guard.is_none(),
+            let ExprLoop(ref block, _) = arms[0].body.node,
+            block.stmts.is_empty(),
+            let Some(ref loopexpr) = block.expr,
+            let ExprMatch(_, ref innerarms, MatchSource::ForLoopDesugar) = loopexpr.node,
+            innerarms.len() == 2 && innerarms[0].pats.len() == 1,
+            let PatKind::TupleStruct(_, Some(ref somepats)) = innerarms[0].pats[0].node,
+            somepats.len() == 1
+        ], {
+            return Some((&somepats[0],
+                         &iterargs[0],
+                         &innerarms[0].body));
+        }
+    }
+    None
+}
+
 /// Unsugar a `hir` range.
 pub fn unsugar_range(expr: &Expr) -> Option<UnsugaredRange> {
     // To be removed when ranges get stable.
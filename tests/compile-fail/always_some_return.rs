@@ -0,0 +1,54 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![deny(always_some_return)]
+#![allow(unused, needless_return)]
+
+fn simple() -> Option<u32> { //~ERROR this function always returns `Some(..)`
+//~^ NOTE consider returning the value without the `Option`
+    Some(1)
+}
+
+fn branches(x: u32) -> Option<u32> { //~ERROR this function always returns `Some(..)`
+//~^ NOTE consider returning the value without the `Option`
+    if x == 0 {
+        Some(1)
+    } else {
+        match x {
+            1 => Some(2),
+            _ => Some(x * 2),
+        }
+    }
+}
+
+fn early_return(x: u32) -> Option<u32> { //~ERROR this function always returns `Some(..)`
+//~^ NOTE consider returning the value without the `Option`
+    if x == 0 {
+        return Some(0);
+    }
+    Some(x - 1)
+}
+
+fn sometimes_none(x: u32) -> Option<u32> { // ok, can return `None`
+    if x == 0 {
+        return None;
+    }
+    Some(x - 1)
+}
+
+fn closure_return(v: &[u32]) -> Option<usize> { // ok, the closure's `return` is not ours
+    let f = |x: &u32| -> Option<u32> { return None; };
+    Some(v.iter().filter_map(f).count())
+}
+
+struct S;
+
+impl Iterator for S {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> { // ok, trait impl
+        Some(42)
+    }
+}
+
+fn main() {
+}
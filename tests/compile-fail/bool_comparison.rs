@@ -20,4 +20,18 @@ fn main() {
     //~^ ERROR equality checks against false can be replaced by a negation
     //~| HELP try simplifying it as shown:
     //~| SUGGESTION if !x { "yes" } else { "no" };
+
+    assert!(x == true);
+    //~^ ERROR equality checks against a boolean in `assert!` are unnecesary
+    //~| HELP try simplifying it as shown:
+    //~| SUGGESTION assert!(x);
+    assert!(x == false, "x is {}", x);
+    //~^ ERROR equality checks against a boolean in `assert!` are unnecesary
+    //~| HELP try simplifying it as shown:
+    //~| SUGGESTION assert!(!x, "x is {}", x);
+    assert!(false == x);
+    //~^ ERROR equality checks against a boolean in `assert!` are unnecesary
+    //~| HELP try simplifying it as shown:
+    //~| SUGGESTION assert!(!x);
+    assert!(x);
 }
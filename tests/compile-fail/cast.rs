@@ -62,3 +62,52 @@ fn main() {
     1i32 as isize; // Neither should this
     1i32 as usize; //~ERROR casting i32 to usize may lose the sign of the value
 }
+
+#[deny(cast_lossless)]
+#[allow(unused_variables)]
+fn lossless() {
+    let x = 1u8;
+    let y = 1i16;
+
+    let _ = x as u16;
+    //~^ ERROR casting u8 to u16 may become silently lossy
+    //~| HELP use a `From` conversion instead
+    //~| SUGGESTION let _ = u16::from(x);
+    let _ = x as i32;
+    //~^ ERROR casting u8 to i32 may become silently lossy
+    //~| HELP use a `From` conversion instead
+    //~| SUGGESTION let _ = i32::from(x);
+    let _ = y as i64;
+    //~^ ERROR casting i16 to i64 may become silently lossy
+    //~| HELP use a `From` conversion instead
+    //~| SUGGESTION let _ = i64::from(y);
+
+    // not lossless
+    let _ = y as u64;
+    let _ = x as i8;
+    let _ = 1u32 as u16;
+    // same width
+    let _ = x as u8;
+    // platform dependent
+    let _ = x as usize;
+    let _ = y as isize;
+}
+
+#[deny(cast_possible_truncation_tryfrom)]
+#[allow(unused_variables)]
+fn truncation_tryfrom() {
+    let x = 1u32;
+
+    let _ = x as u8;
+    //~^ ERROR casting u32 to u8 silently truncates the value
+    //~| HELP if the truncation is intended, make it explicit: `(x & 0xff) as u8`
+    let _ = 1i64 as u16;
+    //~^ ERROR casting i64 to u16 silently truncates the value
+    //~| HELP if the truncation is intended, make it explicit: `(1i64 & 0xffff) as u16`
+
+    // no truncation
+    let _ = 1u8 as u8;
+    let _ = x as u64;
+    // signed targets are left to `cast_possible_truncation`
+    let _ = x as i8;
+}
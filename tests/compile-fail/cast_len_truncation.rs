@@ -0,0 +1,22 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![deny(cast_len_truncation)]
+#![allow(unused)]
+
+fn main() {
+    let v = vec![1, 2, 3];
+    let s = "foo";
+
+    let _ = v.len() as u16; //~ERROR casting the length of a collection to u16 may truncate it
+    //~^ HELP check that `v.len()` is at most `u16::MAX` before casting, or use a wider type
+    let _ = v.len() as i32; //~ERROR casting the length of a collection to i32 may truncate it
+    //~^ HELP check that `v.len()` is at most `i32::MAX` before casting, or use a wider type
+    let _ = s.len() as u8; //~ERROR casting the length of a collection to u8 may truncate it
+    //~^ HELP check that `s.len()` is at most `u8::MAX` before casting, or use a wider type
+
+    // ok
+    let _ = v.len() as u64;
+    let _ = v.len() as isize;
+    let _ = (v.len() / 2) as u64;
+    let _ = v[0] as u16;
+}
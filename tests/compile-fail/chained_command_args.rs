@@ -0,0 +1,28 @@
+#![feature(plugin, custom_attribute)]
+#![plugin(clippy)]
+#![deny(chained_command_args)]
+#![allow(unused)]
+
+use std::process::Command;
+
+fn main() {
+    let _ = Command::new("ls").arg("-l").arg("-a").arg("-h").arg("/tmp").output();
+    //~^ ERROR 4 consecutive calls to `.arg(..)`
+    //~| HELP use `.args(&[..])` instead
+    //~| SUGGESTION let _ = Command::new("ls").args(&["-l", "-a", "-h", "/tmp"]).output();
+
+    let mut cmd = Command::new("ls");
+    cmd.arg("-l").arg("-a").arg("-h");
+    //~^ ERROR 3 consecutive calls to `.arg(..)`
+    //~| HELP use `.args(&[..])` instead
+    //~| SUGGESTION cmd.args(&["-l", "-a", "-h"]);
+
+    // ok
+    Command::new("ls").arg("-l").arg("-a");
+    Command::new("ls").arg("-l").current_dir("/").arg("-a").arg("/tmp");
+}
+
+#[chained_command_args = "4"]
+fn configured() {
+    let _ = Command::new("ls").arg("-l").arg("-a").arg("-h").arg("/tmp").output(); // ok
+}
@@ -0,0 +1,31 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![deny(cmp_option_none)]
+
+struct NotEq;
+
+fn main() {
+    let x = Some(42);
+
+    if x == None { //~ERROR comparing an `Option` to `None`, consider using `is_none()`
+                   //~^ HELP try this
+                   //~| SUGGESTION if x.is_none() {
+    }
+
+    if x != None { //~ERROR comparing an `Option` to `None`, consider using `is_some()`
+                   //~^ HELP try this
+                   //~| SUGGESTION if x.is_some() {
+    }
+
+    if Option::None == x { //~ERROR comparing an `Option` to `None`, consider using `is_none()`
+                           //~^ HELP try this
+                           //~| SUGGESTION if x.is_none() {
+    }
+
+    if x == Some(42) {
+    }
+
+    let y: Option<NotEq> = None;
+    if y.is_none() {
+    }
+}
@@ -226,4 +226,32 @@ fn ifs_same_cond() {
     }
 }
 
+fn baz(_: u8, _: u8) {}
+
+#[deny(if_same_call_different_arg)]
+fn if_same_call_different_arg(c: bool, v: &mut Vec<u8>) {
+    if c { //~ERROR this `if` has branches which only differ in one argument
+        baz(1, 2)
+    } else {
+        baz(1, 3)
+    }
+
+    if c { v.push(1) } else { v.push(2) }
+    //~^ ERROR this `if` has branches which only differ in one argument
+    //~| HELP consider moving the `if` into the argument
+    //~| SUGGESTION v.push(if c { 1 } else { 2 })
+
+    if c { // ok, two arguments differ
+        baz(1, 2)
+    } else {
+        baz(2, 3)
+    }
+
+    if c { // ok, not the same function
+        baz(1, 2)
+    } else {
+        bar(1)
+    }
+}
+
 fn main() {}
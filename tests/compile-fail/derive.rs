@@ -42,9 +42,55 @@ struct Qux;
 
 impl Clone for Qux {
 //~^ ERROR you are implementing `Clone` explicitly on a `Copy` type
+//~| HELP consider using `#[derive(Clone)]` instead
     fn clone(&self) -> Self { Qux }
 }
 
+#[derive(Copy)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+impl Clone for Point {
+//~^ ERROR you are implementing `Clone` explicitly on a `Copy` type
+//~| HELP this implementation only copies `self`, consider using `#[derive(Clone)]` instead
+    fn clone(&self) -> Self { *self }
+}
+
+#[derive(Copy)]
+struct Rebuilt {
+    x: i32,
+    y: i32,
+}
+
+impl Clone for Rebuilt {
+//~^ ERROR you are implementing `Clone` explicitly on a `Copy` type
+//~| HELP consider using `#[derive(Clone)]` instead
+    fn clone(&self) -> Self { Rebuilt { x: self.x, y: self.y } }
+}
+
+#[derive(Copy)]
+struct TupleRebuilt(i32, i32);
+
+impl Clone for TupleRebuilt {
+//~^ ERROR you are implementing `Clone` explicitly on a `Copy` type
+//~| HELP consider using `#[derive(Clone)]` instead
+    fn clone(&self) -> Self { TupleRebuilt(self.0, self.1) }
+}
+
+#[derive(Copy)]
+struct Swapped {
+    x: i32,
+    y: i32,
+}
+
+// not a trivial copy, only the `Copy` note
+impl Clone for Swapped {
+//~^ ERROR you are implementing `Clone` explicitly on a `Copy` type
+    fn clone(&self) -> Self { Swapped { x: self.y, y: self.x } }
+}
+
 // See #666
 #[derive(Copy)]
 struct Lt<'a> {
@@ -86,4 +132,61 @@ impl<T> Clone for Generic<T> {
     fn clone(&self) -> Self { unimplemented!() }
 }
 
+struct AllDefault {
+    a: u32,
+    b: String,
+    c: Option<bool>,
+    d: (f64, bool),
+}
+
+impl Default for AllDefault {
+//~^ ERROR this `Default` implementation only sets every field to its default value
+//~| HELP consider using `#[derive(Default)]`
+    fn default() -> AllDefault {
+        AllDefault {
+            a: 0,
+            b: String::new(),
+            c: None,
+            d: (0.0, false),
+        }
+    }
+}
+
+struct TupleDefault(u8, Vec<u8>);
+
+impl Default for TupleDefault {
+//~^ ERROR this `Default` implementation only sets every field to its default value
+//~| HELP consider using `#[derive(Default)]`
+    fn default() -> Self {
+        TupleDefault(0, Default::default())
+    }
+}
+
+// Ok, one field is not set to its default value
+struct NotAllDefault {
+    a: u32,
+    b: String,
+}
+
+impl Default for NotAllDefault {
+    fn default() -> NotAllDefault {
+        NotAllDefault {
+            a: 42,
+            b: String::new(),
+        }
+    }
+}
+
+// Ok, enums cannot derive `Default`
+enum Choice {
+    A(u32),
+    B,
+}
+
+impl Default for Choice {
+    fn default() -> Choice {
+        Choice::A(0)
+    }
+}
+
 fn main() {}
@@ -0,0 +1,40 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![crate_type = "lib"]
+#![deny(exit_in_library)]
+
+use std::process;
+
+pub fn parse(s: &str) -> u32 {
+    match s.parse() {
+        Ok(n) => n,
+        Err(_) => process::exit(1), //~ERROR calling `process::exit` in a library
+                                    //~^ HELP consider returning an error to the caller instead
+    }
+}
+
+pub fn check(valid: bool) {
+    if !valid {
+        std::process::abort(); //~ERROR calling `process::abort` in a library
+                               //~^ HELP consider returning an error to the caller instead
+    }
+}
+
+pub fn fail(n: u32) {
+    if n > 42 {
+        panic!("too big: {}", n); //~ERROR calling `panic!` in a library
+                                  //~^ HELP consider returning an error to the caller instead
+    }
+    panic!("always"); //~ERROR calling `panic!` in a library
+                      //~^ HELP consider returning an error to the caller instead
+}
+
+pub fn ok(n: u32) -> Result<u32, String> {
+    // assertions are fine
+    assert!(n > 0);
+    if n > 42 {
+        Err(format!("too big: {}", n))
+    } else {
+        Ok(n)
+    }
+}
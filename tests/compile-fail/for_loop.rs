@@ -113,6 +113,11 @@ fn main() {
         //~^ ERROR `i` is used to index `vec`. Consider using `for (i, item) in vec.iter().enumerate()`
         println!("{} {}", vec[i], i);
     }
+    for i in 0..vec.len() {
+        //~^ ERROR `i` is only used to index `vec`. Consider using `for item1 in &vec`
+        let item = vec[i];
+        println!("{}", item);
+    }
     for i in 0..vec.len() {      // not an error, indexing more than one variable
         println!("{} {}", vec[i], vec2[i]);
     }
@@ -370,3 +375,72 @@ fn test_for_kv_map() {
         let _k = k;
     }
 }
+
+#[deny(manual_memcpy)]
+#[allow(needless_range_loop)]
+fn manual_memcpy(src: &[i32], dst: &mut [i32], dst2: &mut Vec<i32>) {
+    for i in 0..src.len() { dst[i] = src[i]; }
+    //~^ ERROR it looks like you're manually copying between slices
+    //~| HELP try replacing the loop by
+    //~| SUGGESTION dst[..src.len()].clone_from_slice(&src[..src.len()])
+
+    for i in 0..5 { dst2[i + 10] = src[i]; }
+    //~^ ERROR it looks like you're manually copying between slices
+    //~| HELP try replacing the loop by
+    //~| SUGGESTION dst2[10..15].clone_from_slice(&src[..5])
+
+    for i in 10..src.len() { dst[i - 10] = src[i]; }
+    //~^ ERROR it looks like you're manually copying between slices
+    //~| HELP try replacing the loop by
+    //~| SUGGESTION dst[..src.len() - 10].clone_from_slice(&src[10..src.len()])
+
+    // ok, not a plain copy
+    for i in 0..src.len() { dst[i] = src[i] + 1; }
+    for i in 0..src.len() { dst[i] = src[i * 2]; }
+    for i in 0..src.len() { dst[i] = src[0]; }
+
+    // ok, copying within the same slice
+    for i in 0..5 { dst[i] = dst[i + 5]; }
+}
+
+#[deny(manual_filter_collect)]
+fn manual_filter_collect(v: Vec<i32>, w: &[i32]) {
+    let mut evens = Vec::new();
+    for x in v {
+    //~^ ERROR it looks like you're manually filtering elements into a `Vec`
+    //~| NOTE consider using `let evens: Vec<_> = v.into_iter().filter(|x| x % 2 == 0).collect();`
+        if x % 2 == 0 {
+            evens.push(x);
+        }
+    }
+
+    let mut big = Vec::with_capacity(w.len());
+    for x in w { if *x > 10 { big.push(x); } }
+    //~^ ERROR it looks like you're manually filtering elements into a `Vec`
+    //~| NOTE consider using `let big: Vec<_> = w.into_iter().filter(|x| *x > 10).collect();`
+
+    // ok, the `Vec` was not created just before the loop
+    let mut odds = Vec::new();
+    odds.push(1);
+    for x in w {
+        if x % 2 == 1 {
+            odds.push(*x);
+        }
+    }
+
+    // ok, the condition looks at the `Vec`
+    let mut firsts = Vec::new();
+    for x in w {
+        if firsts.len() < 3 {
+            firsts.push(x);
+        }
+    }
+
+    // ok, something else than the element is pushed
+    let mut doubled = Vec::new();
+    for x in w {
+        if *x > 0 {
+            doubled.push(x * 2);
+        }
+    }
+}
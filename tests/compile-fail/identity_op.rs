@@ -27,4 +27,22 @@ fn main() {
 
     x & NEG_ONE;  //no error, as we skip lookups (for now)
     -1 & x;       //~ERROR the operation is ineffective
+
+    x ^ 0;        //~ERROR the operation is ineffective
+    0 ^ x;        //~ERROR the operation is ineffective
+    x ^ 1;
+    x | 1;
+
+    x & 0;        //~ERROR this operation will always return `0`
+    0 & x;        //~ERROR this operation will always return `0`
+    x & 1;
+    x & ZERO;     //no error, as we skip lookups (for now)
+
+    let y = 2u32;
+    y | 0;        //~ERROR the operation is ineffective
+    y & 0;        //~ERROR this operation will always return `0`
+
+    y << 0;       //~ERROR the operation is ineffective
+    y >> 0;       //~ERROR the operation is ineffective
+    y << 1;
 }
@@ -0,0 +1,32 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![deny(if_let_ignored_err)]
+#![allow(unused)]
+
+fn parse(s: &str) -> Result<u32, String> {
+    s.parse().map_err(|_| s.to_owned())
+}
+
+fn main() {
+    if let Ok(x) = parse("42") { //~ERROR the `Err` case of this `if let Ok(..)` is silently ignored
+    //~| NOTE consider handling the error in an `else` block
+        println!("{}", x);
+    }
+
+    // ok, there is an `else` block
+    if let Ok(x) = parse("42") {
+        println!("{}", x);
+    } else {
+        // a parse error means the default is fine
+    }
+
+    // ok, not a `Result`
+    if let Some(x) = Some(42) {
+        println!("{}", x);
+    }
+
+    // ok, the error is handled
+    if let Err(e) = parse("42") {
+        println!("{}", e);
+    }
+}
@@ -0,0 +1,21 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![deny(infallible_write_unwrap)]
+
+use std::fmt::Write as FmtWrite;
+use std::io::Write;
+
+fn main() {
+    let x = 42;
+
+    let mut s = String::new();
+    write!(s, "{}", x).unwrap(); //~ERROR called `unwrap()` on the result of writing to an in-memory buffer
+    writeln!(s, "{}", x).unwrap(); //~ERROR called `unwrap()` on the result of writing to an in-memory buffer
+    let _ = write!(s, "{}", x);
+
+    let mut v: Vec<u8> = Vec::new();
+    write!(v, "{}", x).unwrap(); //~ERROR called `unwrap()` on the result of writing to an in-memory buffer
+
+    // writing to stdout can fail
+    write!(std::io::stdout(), "{}", x).unwrap();
+}
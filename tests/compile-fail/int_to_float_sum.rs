@@ -0,0 +1,22 @@
+#![feature(plugin, iter_arith)]
+#![plugin(clippy)]
+#![deny(int_to_float_sum)]
+#![allow(unused)]
+
+fn main() {
+    let big: Vec<u64> = vec![1, 2, 3];
+    let medium: Vec<i32> = vec![1, 2, 3];
+    let small: Vec<u16> = vec![1, 2, 3];
+
+    let _: f64 = big.iter().map(|&x| x as f64).sum();
+    //~^ ERROR the integers are rounded when cast to `f64` and the errors accumulate in the sum
+    //~| NOTE if the sum of the integers cannot overflow, consider summing them first and casting the result
+    let _: f32 = medium.iter().map(|&x| x as f32).sum();
+    //~^ ERROR the integers are rounded when cast to `f32` and the errors accumulate in the sum
+    //~| NOTE if the sum of the integers cannot overflow, consider summing them first and casting the result
+
+    // ok, the casts are exact
+    let _: f64 = medium.iter().map(|&x| x as f64).sum();
+    let _: f32 = small.iter().map(|&x| x as f32).sum();
+    let _: u64 = big.iter().map(|&x| x * 2).sum();
+}
@@ -0,0 +1,29 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![deny(integer_division_to_float)]
+
+fn main() {
+    let a = 1;
+    let b = 2;
+
+    // `let r: f64 = 1 / 2;` does not type-check, the division must be casted
+    let _ = (1 / 2) as f64; //~ERROR integer division casted to a float
+                            //~^ HELP cast the operands instead
+                            //~| SUGGESTION let _ = 1 as f64 / 2 as f64;
+
+    let _: f32 = (a / b) as f32; //~ERROR integer division casted to a float
+                                 //~^ HELP cast the operands instead
+                                 //~| SUGGESTION let _: f32 = a as f32 / b as f32;
+
+    let _ = ((a + 1) / b) as f64; //~ERROR integer division casted to a float
+                                  //~^ HELP cast the operands instead
+                                  //~| SUGGESTION let _ = (a + 1) as f64 / b as f64;
+
+    // ok, the result stays an integer
+    let _ = a / b;
+    let _ = (a / b) as u64;
+
+    // ok, this is already a float division
+    let _ = (1.0 / 2.0) as f32;
+    let _ = a as f64 / b as f64;
+}
@@ -0,0 +1,32 @@
+#![feature(plugin, custom_attribute)]
+#![plugin(clippy)]
+#![deny(large_tuple)]
+#![allow(dead_code, unused_variables)]
+
+type Small = (u8, u8, u8, u8);
+type Large = (u8, u8, u8, u8, u8); //~ERROR this tuple type has 5 elements
+//~| NOTE consider using a struct with named fields instead
+
+fn small(p: (u32, u32, u32, u32)) {}
+
+fn large(p: (u32, u32, u8, u8, u8)) {} //~ERROR this tuple type has 5 elements
+//~| NOTE consider using a struct with named fields instead
+
+fn ret() -> Option<(u8, u8, u8, u8, u8)> { //~ERROR this tuple type has 5 elements
+//~| NOTE consider using a struct with named fields instead
+    None
+}
+
+fn nested(p: (u8, (u8, u8, u8, u8, u8, u8))) {} //~ERROR this tuple type has 6 elements
+//~| NOTE consider using a struct with named fields instead
+
+trait Draw {
+    fn draw(&self, p: (u32, u32, u8, u8, u8)); //~ERROR this tuple type has 5 elements
+    //~| NOTE consider using a struct with named fields instead
+}
+
+#[large_tuple = "5"]
+fn allowed(p: (u32, u32, u8, u8, u8)) {}
+
+fn main() {
+}
@@ -0,0 +1,70 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![deny(leading_continue)]
+#![allow(unused)]
+
+fn main() {
+    let v = vec![1, 2, 3];
+
+    for x in &v {
+        if *x % 2 != 0 { //~ERROR this loop body starts with a `continue` guard
+        //~^ HELP consider putting the rest of the body in an `if` instead: `if !(*x % 2 != 0) { .. }`
+            continue;
+        }
+        println!("{}", x);
+    }
+
+    // ok, the guard is not the first statement
+    let mut i = 0;
+    while i < 10 {
+        i += 1;
+        if !v.contains(&i) {
+            continue;
+        }
+        println!("{}", i);
+    }
+
+    let mut it = v.iter();
+    while let Some(x) = it.next() {
+        if x.is_power_of_two() { //~ERROR this loop body starts with a `continue` guard
+        //~^ HELP consider putting the rest of the body in an `if` instead: `if !x.is_power_of_two() { .. }`
+            continue
+        }
+        println!("{}", x);
+        println!("{}", x + 1);
+    }
+
+    loop {
+        if !v.is_empty() { //~ERROR this loop body starts with a `continue` guard
+        //~^ HELP consider putting the rest of the body in an `if` instead: `if v.is_empty() { .. }`
+            continue;
+        }
+        break;
+    }
+
+    // ok, nothing after the guard
+    for x in &v {
+        if *x == 2 {
+            continue;
+        }
+    }
+
+    // ok, the `if` does something else
+    for x in &v {
+        if *x == 2 {
+            println!("two");
+            continue;
+        }
+        println!("{}", x);
+    }
+
+    // ok, labeled `continue`
+    'outer: for x in &v {
+        for y in &v {
+            if x == y {
+                continue 'outer;
+            }
+            println!("{}", y);
+        }
+    }
+}
@@ -105,6 +105,39 @@ fn main() {
         //~|SUGGESTION !hie.is_empty()
         println!("Or this!");
     }
+    if hie.len() >= 1 {
+        //~^ERROR length comparison to one
+        //~|HELP consider using `is_empty`
+        //~|SUGGESTION !hie.is_empty()
+        println!("Or this!");
+    }
+    if 0 < hie.len() {
+        //~^ERROR length comparison to zero
+        //~|HELP consider using `is_empty`
+        //~|SUGGESTION !hie.is_empty()
+        println!("Or this!");
+    }
+    if 1 <= hie.len() {
+        //~^ERROR length comparison to one
+        //~|HELP consider using `is_empty`
+        //~|SUGGESTION !hie.is_empty()
+        println!("Or this!");
+    }
+    if hie.len() < 1 {
+        //~^ERROR length comparison to one
+        //~|HELP consider using `is_empty`
+        //~|SUGGESTION hie.is_empty()
+        println!("Or this!");
+    }
+    if 0 != hie.len() {
+        //~^ERROR length comparison to zero
+        //~|HELP consider using `is_empty`
+        //~|SUGGESTION !hie.is_empty()
+        println!("Or this!");
+    }
+    if hie.len() > 1 { // no error, this is not an emptiness check
+        println!("Or this!");
+    }
     assert!(!hie.is_empty());
 
     let wie : &WithIsEmpty = &Wither;
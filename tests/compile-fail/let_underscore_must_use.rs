@@ -0,0 +1,26 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![deny(let_underscore_must_use)]
+
+fn fallible() -> Result<u32, ()> {
+    Ok(0)
+}
+
+#[must_use]
+struct Token;
+
+fn main() {
+    let _ = fallible(); //~ERROR discarding a value of a `#[must_use]` type
+    //~| NOTE consider handling the error, or use `.ok()` to show that it is ignored on purpose
+
+    let _ = Token; //~ERROR discarding a value of a `#[must_use]` type
+    //~| NOTE consider using the value, or explain why it can be ignored
+
+    // explicitly ignored
+    let _ = fallible().ok();
+
+    // not `#[must_use]`
+    let _ = String::from("guard");
+    let _ = 42;
+    let _x = fallible();
+}
@@ -1,9 +1,12 @@
 #![feature(plugin)]
 #![plugin(clippy)]
 
-#![deny(needless_lifetimes, unused_lifetimes)]
+#![deny(needless_lifetimes, unused_lifetimes, needless_static_bound)]
 #![allow(dead_code)]
 
+use std::any::Any;
+use std::fmt::Debug;
+
 fn distinct_lifetimes<'a, 'b>(_x: &'a u8, _y: &'b u8, _z: u8) { }
 //~^ERROR explicit lifetimes given
 
@@ -130,3 +133,21 @@ fn trait_bound<'a, T: WithLifetime<'a>>(_: &'a u8, _: T) { unimplemented!() }
 
 fn main() {
 }
+
+fn show<T: Debug + 'static>(x: &T) { //~ERROR this `'static` bound is not needed
+    println!("{:?}", x);
+}
+
+fn show_all<T: Debug + 'static>(xs: &[T]) { //~ERROR this `'static` bound is not needed
+    for x in xs {
+        println!("{:?}", x);
+    }
+}
+
+fn store<T: Any + 'static>(x: T) -> Box<Any + 'static> { // no error, stored by value
+    Box::new(x)
+}
+
+fn store_ref<T: Any + Clone + 'static>(x: &T) -> Box<Any + 'static> { // no error, coerced to a trait object
+    Box::new(x.clone())
+}
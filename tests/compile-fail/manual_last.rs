@@ -0,0 +1,47 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![deny(manual_last)]
+#![allow(unused)]
+
+fn main() {
+    let v = vec![1, 2, 3];
+
+    let mut last = None;
+    for x in &v { //~ERROR it looks like you're manually looking for the last element
+    //~^ NOTE consider using `let last = v.iter().last();` instead
+        last = Some(x);
+    }
+
+    let mut last = None;
+    for x in &v { //~ERROR it looks like you're manually looking for the last element
+    //~^ NOTE consider using `let last = v.iter().last().cloned();` instead
+        last = Some(x.clone());
+    }
+
+    let strings = vec![String::from("foo")];
+    let mut last = None;
+    for s in strings { //~ERROR it looks like you're manually looking for the last element
+    //~^ NOTE consider using `let last = strings.into_iter().last();` instead
+        last = Some(s.clone());
+    }
+
+    // ok, starts with a value
+    let mut last = Some(&0);
+    for x in &v {
+        last = Some(x);
+    }
+
+    // ok, does more than storing the element
+    let mut last = None;
+    for x in &v {
+        last = Some(x);
+        println!("{}", x);
+    }
+
+    // ok, not declared right before the loop
+    let mut last = None;
+    let w = vec![4, 5];
+    for x in &w {
+        last = Some(x);
+    }
+}
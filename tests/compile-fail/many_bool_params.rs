@@ -0,0 +1,23 @@
+#![feature(plugin, custom_attribute)]
+#![plugin(clippy)]
+#![deny(many_bool_params)]
+#![allow(dead_code, unused_variables)]
+
+fn two(filled: bool, shadow: bool) {}
+
+fn three(filled: bool, shadow: bool, antialias: bool) {} //~ERROR this function has 3 `bool` parameters
+//~| NOTE consider using an options struct or enums with descriptive variants instead
+
+fn mixed(x: u32, filled: bool, y: u32, shadow: bool, antialias: bool) {} //~ERROR this function has 3 `bool` parameters
+//~| NOTE consider using an options struct or enums with descriptive variants instead
+
+trait Draw {
+    fn draw(&self, filled: bool, shadow: bool, antialias: bool); //~ERROR this function has 3 `bool` parameters
+    //~| NOTE consider using an options struct or enums with descriptive variants instead
+}
+
+#[many_bool_params = "3"]
+fn allowed(filled: bool, shadow: bool, antialias: bool) {}
+
+fn main() {
+}
@@ -17,6 +17,10 @@ fn map_clone_iter() {
                           //~^ HELP try
     x.iter().map(Clone::clone); //~ ERROR you seem to be using .map()
                                 //~^ HELP try
+    x.iter().map(ToOwned::to_owned); //~ ERROR you seem to be using .map()
+                                     //~^ HELP try
+    let s = ["a", "b"];
+    let _: Vec<String> = s.iter().cloned().map(ToOwned::to_owned).collect(); // ok, `str::to_owned` isn't `clone`
 }
 
 fn map_clone_option() {
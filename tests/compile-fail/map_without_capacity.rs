@@ -0,0 +1,66 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![deny(map_without_capacity)]
+#![allow(unused)]
+
+use std::collections::HashMap;
+
+fn make_vec() -> Vec<u32> {
+    vec![1, 2, 3]
+}
+
+fn main() {
+    let v = vec![1, 2, 3];
+
+    let mut m = HashMap::new(); //~ERROR this map is filled from a collection of known length
+    //~^ HELP consider allocating enough room up front
+    //~| SUGGESTION let mut m = HashMap::with_capacity(v.len());
+    for x in &v {
+        m.insert(*x, 0);
+    }
+
+    let mut m = HashMap::new(); //~ERROR this map is filled from a collection of known length
+    //~^ HELP consider allocating enough room up front
+    //~| SUGGESTION let mut m = HashMap::with_capacity(v.len());
+    for x in v.iter() {
+        m.insert(*x, x * 2);
+        m.insert(x + 10, x * 3);
+    }
+
+    // ok, not only inserting
+    let mut m = HashMap::new();
+    for x in &v {
+        m.insert(*x, 0);
+        println!("{}", x);
+    }
+
+    // ok, unknown length
+    let mut m = HashMap::new();
+    for x in v.iter().filter(|&&x| x > 1) {
+        m.insert(*x, 0);
+    }
+
+    // ok, the collection is not a local or a field
+    let mut m = HashMap::new();
+    for x in make_vec() {
+        m.insert(x, 0);
+    }
+
+    let mut m = HashMap::new();
+    for x in make_vec().iter() {
+        m.insert(*x, 0);
+    }
+
+    // ok, already has a capacity
+    let mut m = HashMap::with_capacity(v.len());
+    for x in &v {
+        m.insert(*x, 0);
+    }
+
+    let mut m = HashMap::new(); //~ERROR this map is filled from a collection of known length
+    //~^ HELP consider allocating enough room up front
+    //~| SUGGESTION let mut m = HashMap::with_capacity(v.len());
+    for x in v {
+        m.insert(x, 0);
+    }
+}
@@ -0,0 +1,37 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![deny(match_literals_contains)]
+#![allow(unused, match_as_bool)]
+
+fn main() {
+    let x = 42;
+    let c = 'a';
+
+    let _ = match x { //~ERROR this match is a membership test on literals
+    //~| NOTE consider using `[1, 2, 3].contains(&x)`
+        1 | 2 | 3 => true,
+        _ => false,
+    };
+
+    let _ = match c { //~ERROR this match is a membership test on literals
+    //~| NOTE consider using `!['a', 'e', 'i', 'o', 'u'].contains(&c)`
+        'a' | 'e' | 'i' | 'o' | 'u' => false,
+        _ => true,
+    };
+
+    // ok
+    let _ = match x {
+        1 | 2 => true,
+        _ => false,
+    };
+
+    let _ = match x {
+        1 ... 3 | 5 | 7 => true,
+        _ => false,
+    };
+
+    let _ = match "foo" {
+        "a" | "b" | "c" => true,
+        _ => false,
+    };
+}
@@ -47,12 +47,22 @@ fn single_match(){
         _ => {}
     }
 
-    // Not linted (pattern guards used)
-    match x {
+    match x { //~ ERROR you seem to be trying to use match
+              //~^ HELP try
+              //~| SUGGESTION if let Some(y) = x { if y == 0 { println!("{:?}", y) } }
         Some(y) if y == 0 => println!("{:?}", y),
         _ => ()
     }
 
+    // Not linted (the `else` block would also run when the guard fails)
+    match x {
+        Some(y) if y == 0 => println!("{:?}", y),
+        _ => {
+            let z = 42;
+            println!("{}", z);
+        }
+    }
+
     // Not linted (no block with statements in the single arm)
     match z {
         (2...3, 7...9) => println!("{:?}", z),
@@ -134,6 +144,47 @@ fn match_bool() {
     };
 }
 
+fn match_as_bool() {
+    let x = 42;
+    let c = 'a';
+
+    let _ = match x { //~ ERROR this match only produces a boolean
+                      //~^ HELP try this
+                      //~| SUGGESTION x == 1
+        1 => true,
+        _ => false,
+    };
+
+    let _ = match c { //~ ERROR this match only produces a boolean
+                      //~^ HELP try this
+                      //~| SUGGESTION c != 'a' && c != 'b'
+        'a' | 'b' => false,
+        _ => true,
+    };
+
+    let _ = match x + 1 { //~ ERROR this match only produces a boolean
+        1 | 2 => true,
+        _ => false,
+    };
+
+    // Not linted
+    let _ = match x {
+        1 ... 10 => true,
+        _ => false,
+    };
+
+    let _ = match x {
+        1 => true,
+        2 => true,
+        _ => false,
+    };
+
+    let _ = match x {
+        1 => 0,
+        _ => 1,
+    };
+}
+
 fn ref_pats() {
     {
         let v = &Some(0);
@@ -203,5 +254,90 @@ fn overlapping() {
     }
 }
 
+fn redundant_pattern_matching() {
+    let opt = Some(42);
+    let res: Result<u8, ()> = Ok(42);
+
+    if let Some(_) = opt { //~ERROR redundant pattern matching, consider using `is_some()`
+                           //~^ HELP try this
+                           //~| SUGGESTION if opt.is_some()
+        println!("some");
+    }
+
+    if let None = opt { //~ERROR redundant pattern matching, consider using `is_none()`
+                        //~^ HELP try this
+                        //~| SUGGESTION if opt.is_none()
+        println!("none");
+    }
+
+    if let Ok(_) = res { //~ERROR redundant pattern matching, consider using `is_ok()`
+                         //~^ HELP try this
+                         //~| SUGGESTION if res.is_ok()
+        println!("ok");
+    }
+
+    if let Err(_) = res { //~ERROR redundant pattern matching, consider using `is_err()`
+                          //~^ HELP try this
+                          //~| SUGGESTION if res.is_err()
+        println!("err");
+    }
+
+    // the value is used
+    if let Some(x) = opt {
+        println!("{}", x);
+    }
+}
+
+fn if_as_match_guard() {
+    let opt = Some(42);
+
+    match opt {
+        Some(x) => if x > 5 { println!("big") } else { println!("small or none") },
+        //~^ ERROR this `if` on the bound value could be a match guard
+        //~| HELP consider using a guard: `Some(x) if x > 5 => { println!("big") }`
+        _ => println!("small or none"),
+    }
+
+    match opt {
+        Some(x) => {
+            //~^ ERROR this `if` on the bound value could be a match guard
+            if x > 5 {
+                println!("big");
+            } else {
+                println!("small or none")
+            }
+        }
+        _ => println!("small or none"),
+    }
+
+    // the `else` branch differs from the wildcard arm
+    match opt {
+        Some(x) => if x > 5 { println!("big") } else { println!("small") },
+        _ => println!("none"),
+    }
+
+    // the condition does not use the binding
+    let flag = true;
+    match opt {
+        Some(_) => if flag { println!("flag") } else { println!("other") },
+        _ => println!("other"),
+    }
+    // `s` is moved out, which is not allowed in a guarded arm
+    let name = Some(String::from("foo"));
+    match name {
+        Some(s) => if s.is_empty() { println!("empty") } else { println!("other") },
+        _ => println!("other"),
+    }
+
+    // ok to lint, `s` is bound by reference
+    let name = Some(String::from("foo"));
+    match name {
+        Some(ref s) => if s.is_empty() { println!("empty") } else { println!("other") },
+        //~^ ERROR this `if` on the bound value could be a match guard
+        //~| HELP consider using a guard: `Some(ref s) if s.is_empty() => { println!("empty") }`
+        _ => println!("other"),
+    }
+}
+
 fn main() {
 }
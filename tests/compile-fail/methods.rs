@@ -104,6 +104,7 @@ struct IteratorFalsePositives {
     foo: u32,
 }
 
+#[allow(always_some_return)]
 impl IteratorFalsePositives {
     fn filter(self) -> IteratorFalsePositives {
         self
@@ -265,6 +266,23 @@ fn or_fun_call() {
     //~^ERROR use of `or_insert` followed by a function call
     //~|HELP try this
     //~|SUGGESTION btree.entry(42).or_insert_with(String::new);
+
+    let opt = Some(1);
+    let _ = opt.or(Some(make()));
+    //~^ERROR use of `or` followed by a function call
+    //~|HELP try this
+    //~|SUGGESTION let _ = opt.or_else(|| Some(make()));
+
+    let res: Result<u64, ()> = Ok(1);
+    let _: Result<u64, ()> = res.or(Ok(make()));
+    //~^ERROR use of `or` followed by a function call
+    //~|HELP try this
+    //~|SUGGESTION let _: Result<u64, ()> = res.or_else(|_| Ok(make()));
+
+    // cheap values wrapped in a constructor are fine
+    let _ = opt.or(Some(2));
+    let _: Result<u64, ()> = res.or(Ok(2));
+    let _ = opt.or(Some(2 + 3));
 }
 
 fn main() {
@@ -281,6 +299,13 @@ fn main() {
     let v = &"str";
     let string = v.to_string();  //~ERROR `(*v).to_owned()` is faster
     let _again = string.to_string();  //~ERROR `String::to_string` is an inefficient way to clone a `String`; use `clone()` instead
+    let _again = string.to_owned();
+    //~^ ERROR `String::to_owned` only clones the `String`
+    //~| HELP use `clone()` instead
+    //~| SUGGESTION let _again = string.clone();
+    let _ = "str".to_owned();
+    let s = &"str";
+    let _ = s.to_owned();
 
     res.ok().expect("disaster!"); //~ERROR called `ok().expect()`
     // the following should not warn, since `expect` isn't implemented unless
@@ -447,3 +472,590 @@ fn single_char_pattern() {
     //~| HELP try using a char instead:
     //~| SUGGESTION x.trim_right_matches('x');
 }
+
+/// Checks implementation of ITER_NTH_ZERO lint
+fn iter_nth_zero() {
+    const ZERO: usize = 0;
+    let v = vec![3, 2, 1];
+
+    let _ = v.iter().nth(0);
+    //~^ ERROR called `.nth(0)` on an Iterator
+    //~| HELP try this
+    //~| SUGGESTION let _ = v.iter().next();
+
+    let _ = "foo".chars().nth(0usize);
+    //~^ ERROR called `.nth(0)` on an Iterator
+    //~| HELP try this
+    //~| SUGGESTION let _ = "foo".chars().next();
+
+    let _ = v.iter().nth(ZERO); //~ERROR called `.nth(0)` on an Iterator
+
+    let n = 1;
+    let _ = v.iter().nth(n);
+    let _ = v.iter().nth(1);
+}
+
+/// Checks implementation of NEEDLESS_COLLECT lint
+fn needless_collect() {
+    let v = vec![3, 2, 1];
+
+    let _ = v.iter().filter(|&x| *x > 1).collect::<Vec<_>>().len();
+    //~^ ERROR avoid allocating a `Vec` just to inspect its length
+    //~| HELP try this
+    //~| SUGGESTION let _ = v.iter().filter(|&x| *x > 1).count();
+
+    let _ = v.iter().map(|x| x + 1).collect::<Vec<_>>().is_empty();
+    //~^ ERROR avoid allocating a `Vec` just to inspect its length
+    //~| HELP try this
+    //~| SUGGESTION let _ = v.iter().map(|x| x + 1).next().is_none();
+
+    let _ = v.into_iter().map(|x| x * 2).filter(|x| *x > 2).collect::<Vec<u32>>().is_empty();
+    //~^ ERROR avoid allocating a `Vec` just to inspect its length
+    //~| HELP try this
+    //~| SUGGESTION let _ = v.into_iter().map(|x| x * 2).filter(|x| *x > 2).next().is_none();
+
+    let v = vec![3, 2, 1];
+    if (0..10).map(|i| v.len() + i).collect::<Vec<_>>().is_empty() {}
+    //~^ ERROR avoid allocating a `Vec` just to inspect its length
+    //~| HELP try this
+    //~| SUGGESTION if (0..10).map(|i| v.len() + i).next().is_none() {}
+
+    let text = "a few words\non two lines";
+    let _ = text.split_whitespace().collect::<Vec<_>>().len();
+    //~^ ERROR avoid allocating a `Vec` just to inspect its length
+    //~| HELP try this
+    //~| SUGGESTION let _ = text.split_whitespace().count();
+    let _ = text.lines().collect::<Vec<&str>>().len();
+    //~^ ERROR avoid allocating a `Vec` just to inspect its length
+    //~| HELP try this
+    //~| SUGGESTION let _ = text.lines().count();
+
+    // the collected `Vec` is reused, this is fine
+    let w: Vec<_> = v.iter().collect();
+    let _ = w.len();
+
+    // not a `Vec`
+    let _ = "foo".chars().collect::<String>().len();
+}
+
+/// Checks implementation of STRING_AS_BYTES_TO_VEC lint
+fn string_as_bytes_to_vec() {
+    let x = 42;
+
+    let _ = x.to_string().as_bytes().to_vec();
+    //~^ ERROR calling `as_bytes().to_vec()` on a `String` copies its buffer
+    //~| HELP use `into_bytes()`
+    //~| SUGGESTION let _ = x.to_string().into_bytes();
+
+    // `&str` must be copied
+    let slice = "foo";
+    let _ = slice.as_bytes().to_vec();
+
+    // the `String` might be used afterwards
+    let s = String::from("foo");
+    let _ = s.as_bytes().to_vec();
+    println!("{}", s);
+}
+
+struct NotAnIterator;
+
+impl NotAnIterator {
+    fn rev(self) -> Self { self }
+    fn next(self) -> Option<u32> { None }
+}
+
+/// Checks implementation of REVERSED_NEXT lint
+fn reversed_next() {
+    let v = vec![3, 2, 1];
+
+    let _ = v.iter().rev().next();
+    //~^ ERROR called `.rev().next()` on a `DoubleEndedIterator`
+    //~| HELP try this
+    //~| SUGGESTION let _ = v.iter().next_back();
+
+    let _ = (0..10).rev().next();
+    //~^ ERROR called `.rev().next()` on a `DoubleEndedIterator`
+    //~| HELP try this
+    //~| SUGGESTION let _ = (0..10).next_back();
+
+    // not double-ended, and not even an iterator
+    let _ = NotAnIterator.rev().next();
+}
+
+/// Checks implementation of ITER_COUNT_ZERO lint
+fn iter_count_zero() {
+    let v = vec![3, 2, 1];
+
+    let _ = v.iter().filter(|&x| *x > 1).count() == 0;
+    //~^ ERROR comparing the `.count()` of an Iterator to zero
+    //~| HELP use `.next()` instead
+    //~| SUGGESTION let _ = v.iter().filter(|&x| *x > 1).next().is_none();
+
+    let _ = v.iter().count() > 0;
+    //~^ ERROR comparing the `.count()` of an Iterator to zero
+    //~| HELP use `.next()` instead
+    //~| SUGGESTION let _ = v.iter().next().is_some();
+
+    let _ = 0 != v.iter().count();
+    //~^ ERROR comparing the `.count()` of an Iterator to zero
+    //~| HELP use `.next()` instead
+    //~| SUGGESTION let _ = v.iter().next().is_some();
+
+    // the actual count is needed
+    let _ = v.iter().count() == 2;
+    let _ = v.iter().count() > 1;
+}
+
+/// Checks implementation of CHECKED_UNWRAP lint
+#[deny(checked_unwrap)]
+fn checked_unwrap() {
+    let x = 42u32;
+    let y = 2u32;
+
+    let _ = x.checked_add(y).unwrap();
+    //~^ ERROR called `.unwrap()` on the result of `.checked_add()`
+    //~| HELP consider using `x + y`
+    let _ = x.checked_sub(1).unwrap();
+    //~^ ERROR called `.unwrap()` on the result of `.checked_sub()`
+    //~| HELP consider using `x - 1`
+    let _ = x.checked_mul(y).unwrap();
+    //~^ ERROR called `.unwrap()` on the result of `.checked_mul()`
+    //~| HELP consider using `x * y`
+    let _ = x.checked_div(y).unwrap();
+    //~^ ERROR called `.unwrap()` on the result of `.checked_div()`
+    //~| HELP consider using `x / y`
+    let _ = x.checked_rem(y).unwrap();
+    //~^ ERROR called `.unwrap()` on the result of `.checked_rem()`
+    //~| HELP consider using `x % y`
+
+    // the intent is documented
+    let _ = x.checked_add(y).expect("x and y are small");
+}
+
+/// Checks implementation of VEC_REMOVE_ZERO lint
+fn vec_remove_zero() {
+    let mut v = vec![3, 2, 1];
+    let i = 1;
+
+    let _ = v.remove(0);
+    //~^ ERROR calling `.remove(0)` on a `Vec` shifts all its remaining elements
+    //~| NOTE consider using a `VecDeque`
+
+    while !v.is_empty() {
+        let _ = v.remove(0);
+        //~^ ERROR calling `.remove(0)` on a `Vec` shifts all its remaining elements
+        //~| NOTE consider using a `VecDeque`
+    }
+
+    let mut v = vec![3, 2, 1];
+    let _ = v.remove(i);
+    let _ = v.remove(1);
+}
+
+fn filter_map_if() {
+    let v = vec![3, 2, 1];
+
+    let _: Vec<_> = v.iter().cloned().filter_map(|x| if x > 1 { Some(x) } else { None }).collect();
+    //~^ ERROR this `.filter_map(..)` only keeps the elements for which a condition holds
+    //~| HELP try
+    //~| SUGGESTION v.iter().cloned().filter(|&x| x > 1)
+
+    let names = vec![String::from("a"), String::new()];
+    let _: Vec<_> = names.into_iter().filter_map(|s| if !s.is_empty() { Some(s) } else { None }).collect();
+    //~^ ERROR this `.filter_map(..)` only keeps the elements for which a condition holds
+    //~| HELP consider using `.filter(..)` instead
+
+    // the element is transformed, `filter_map` is the right choice
+    let _: Vec<_> = v.iter().filter_map(|x| if *x > 1 { Some(x * 2) } else { None }).collect();
+    let _: Vec<_> = v.iter().filter_map(|x| if *x > 1 { None } else { Some(x) }).collect();
+}
+
+fn result_unwrap_in_result_fn() -> std::io::Result<u32> {
+    use std::io;
+
+    let res: Result<u32, io::Error> = Ok(0);
+    let _ = res.unwrap();
+    //~^ ERROR used unwrap() on a Result
+    //~| NOTE the enclosing function returns a compatible `Result`, the error can be passed on with `try!(..)`
+
+    // `()` cannot be converted into an `io::Error`
+    let res: Result<u32, ()> = Ok(0);
+    let _ = res.unwrap(); //~ERROR used unwrap() on a Result
+
+    // `try!` would return from the closure
+    let f = || {
+        let res: Result<u32, io::Error> = Ok(0);
+        res.unwrap() //~ERROR used unwrap() on a Result
+    };
+
+    Ok(f())
+}
+
+#[allow(string_to_owned)]
+fn clone_into_iter_next() {
+    let v = vec![String::from("a"), String::from("b")];
+
+    let _ = v.clone().into_iter().next();
+    //~^ ERROR cloning a whole `Vec` just to get its first element
+    //~| NOTE consider using `v.first().cloned()` instead
+
+    let r = &v;
+    let _ = r.clone().into_iter().next();
+    //~^ ERROR cloning a whole `Vec` just to get its first element
+    //~| NOTE consider using `r.first().cloned()` instead
+
+    // not a `Vec`
+    let _ = "ab".to_owned().clone().into_bytes().into_iter().next();
+    let _ = v.iter().cloned().next();
+}
+
+fn linear_key_search() {
+    let mut map = HashMap::new();
+    map.insert(1u32, "one");
+    let key = 1u32;
+
+    let _ = map.iter().find(|&(k, _)| *k == key);
+    //~^ ERROR searching a key by iterating over the whole map
+    //~| HELP consider looking the key up directly with `map.get(&key)` or `map.contains_key(&key)`
+
+    let _ = map.iter().filter(|&(k, _)| k == &key).next();
+    //~^ ERROR searching a key by iterating over the whole map
+    //~| HELP consider looking the key up directly with `map.get(&key)` or `map.contains_key(&key)`
+
+    let mut tree = BTreeMap::new();
+    tree.insert("a", 1);
+    let _ = tree.iter().find(|&(k, _)| "a" == *k);
+    //~^ ERROR searching a key by iterating over the whole map
+    //~| HELP consider looking the key up directly with `tree.get(&"a")` or `tree.contains_key(&"a")`
+
+    // searching by value
+    let _ = map.iter().find(|&(_, v)| *v == "one");
+    // not a plain key comparison
+    let _ = map.iter().find(|&(k, _)| *k > key);
+    // not a map
+    let v = vec![(1u32, "one")];
+    let _ = v.iter().find(|&&(k, _)| k == key);
+}
+
+fn unnecessary_lazy_evaluation() {
+    let opt: Option<u32> = None;
+    let res: Result<u32, ()> = Ok(1);
+    let n = 2u32;
+
+    let _ = opt.unwrap_or_else(|| 0);
+    //~^ ERROR unnecessary closure used to compute a trivial value
+    //~| HELP use `unwrap_or(..)` instead
+    //~| SUGGESTION let _ = opt.unwrap_or(0);
+    let _ = opt.unwrap_or_else(|| n);
+    //~^ ERROR unnecessary closure used to compute a trivial value
+    //~| HELP use `unwrap_or(..)` instead
+    //~| SUGGESTION let _ = opt.unwrap_or(n);
+    let _ = res.unwrap_or_else(|_| 0);
+    //~^ ERROR unnecessary closure used to compute a trivial value
+    //~| HELP use `unwrap_or(..)` instead
+    //~| SUGGESTION let _ = res.unwrap_or(0);
+    let _ = opt.map(|x| x + 1).unwrap_or_else(|| 0);
+    //~^ ERROR called `map(f).unwrap_or_else(g)` on an Option value
+    //~| NOTE replace `map(|x| x + 1).unwrap_or_else(|| 0)` with `with map_or_else(|| 0, |x| x + 1)`
+    //~| ERROR unnecessary closure used to compute a trivial value
+    //~| HELP use `unwrap_or(..)` instead
+    //~| SUGGESTION let _ = opt.map(|x| x + 1).unwrap_or(0);
+
+    // the closure does some work
+    let _ = opt.unwrap_or_else(|| n * 2);
+    let _ = opt.unwrap_or_else(|| u32::max_value());
+    let s = String::new();
+    let _ = Some(String::new()).unwrap_or_else(|| s);
+    // the error is used
+    let _ = res.unwrap_or_else(|e| { let () = e; 0 });
+}
+
+fn identity_map() {
+    let v = vec![1, 2, 3];
+
+    let _: Vec<_> = v.iter().map(|x| x).filter(|x| **x > 2).collect();
+    //~^ ERROR this `.map(..)` returns its argument unchanged
+    //~| HELP remove it
+    //~| SUGGESTION let _: Vec<_> = v.iter().filter(|x| **x > 2).collect();
+    let _: Vec<_> = v.iter().filter(|x| **x > 2).map(|y| y).collect();
+    //~^ ERROR this `.map(..)` returns its argument unchanged
+    //~| HELP remove it
+    //~| SUGGESTION let _: Vec<_> = v.iter().filter(|x| **x > 2).collect();
+
+    // ok
+    let _: Vec<_> = v.iter().map(|x| x + 1).collect();
+    let _: Vec<_> = v.iter().map(|&x| x).collect();
+    let _ = Some(1).map(|x| x);
+}
+
+fn boxed_round_trip() {
+    let v = vec![1, 2, 3];
+    let s = String::from("foo");
+
+    let _ = v.into_boxed_slice().into_vec();
+    //~^ ERROR calling `into_boxed_slice().into_vec()` gives back the original value
+    //~| HELP remove the conversions
+    //~| SUGGESTION let _ = v;
+    let _ = s.into_boxed_str().into_string();
+    //~^ ERROR calling `into_boxed_str().into_string()` gives back the original value
+    //~| HELP remove the conversions
+    //~| SUGGESTION let _ = s;
+
+    // ok
+    let b = vec![1, 2, 3].into_boxed_slice();
+    let _ = b.into_vec();
+}
+
+fn fold_to_sum() {
+    let v = vec![1, 2, 3];
+
+    let _ = v.iter().fold(0, |acc, x| acc + x);
+    //~^ ERROR this `.fold(..)` computes the sum of the elements
+    //~| HELP use `.sum()` instead
+    //~| SUGGESTION let _ = v.iter().sum();
+    let _ = v.iter().fold(0, |acc, x| x + acc);
+    //~^ ERROR this `.fold(..)` computes the sum of the elements
+    //~| HELP use `.sum()` instead
+    //~| SUGGESTION let _ = v.iter().sum();
+    let _ = v.iter().fold(1, |a, b| a * b);
+    //~^ ERROR this `.fold(..)` computes the product of the elements
+    //~| HELP use `.product()` instead
+    //~| SUGGESTION let _ = v.iter().product();
+
+    // ok
+    let _ = v.iter().fold(1, |acc, x| acc + x);
+    let _ = v.iter().fold(0, |acc, x| acc * x);
+    let _ = v.iter().fold(0, |acc, x| acc - x);
+    let _ = v.iter().fold(0, |acc, x| acc + x * 2);
+    let _ = v.iter().fold(0, |acc, _| acc + 1);
+}
+
+fn filter_map_unwrap() {
+    let v = vec![Some(1), None, Some(3)];
+
+    let _: Vec<_> = v.iter().filter(|x| x.is_some()).map(|x| x.unwrap()).collect();
+    //~^ ERROR called `filter(|x| x.is_some()).map(|x| x.unwrap())` on an Iterator
+    //~| HELP use `filter_map(..)` instead
+    //~| SUGGESTION let _: Vec<_> = v.iter().filter_map(|x| *x).collect();
+    let _: Vec<_> = v.into_iter().filter(|o| o.is_some()).map(|p| p.unwrap()).collect();
+    //~^ ERROR called `filter(|x| x.is_some()).map(|x| x.unwrap())` on an Iterator
+    //~| HELP use `filter_map(..)` instead
+    //~| SUGGESTION let _: Vec<_> = v.into_iter().filter_map(|x| x).collect();
+
+    // ok, the filter is something else
+    let v = vec![Some(1), None, Some(3)];
+    let _: Vec<_> = v.iter().filter(|x| x.is_none()).map(|x| x.unwrap()).collect();
+    let _: Vec<_> = v.iter().filter(|x| x.is_some()).map(|x| x.unwrap() + 1).collect();
+}
+
+fn bytes_as_chars() {
+    let bytes = b"caf\xc3\xa9";
+
+    let _: String = bytes.iter().map(|&b| b as char).collect();
+    //~^ ERROR collecting bytes casted to `char`s into a `String` does not decode UTF-8
+    //~| NOTE non-ASCII bytes become unrelated characters
+    let _ = bytes.iter().cloned().map(|b| b as char).collect::<String>();
+    //~^ ERROR collecting bytes casted to `char`s into a `String` does not decode UTF-8
+    //~| NOTE non-ASCII bytes become unrelated characters
+
+    // ok
+    let _: Vec<char> = bytes.iter().map(|&b| b as char).collect();
+    let _: String = bytes.iter().map(|&b| (b + 1) as char).filter(|c| c.is_alphabetic()).collect();
+    let _: String = "abc".chars().rev().collect();
+}
+
+struct Name(String);
+
+impl Name {
+    fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+fn option_as_ref_deref() {
+    let opt = Some(String::from("foo"));
+    let v = Some(vec![1, 2, 3]);
+
+    let _ = opt.as_ref().map(|s| s.as_str());
+    //~^ ERROR called `as_ref().map(..)` on an `Option` with a closure which only derefs its argument
+    //~| NOTE consider using `opt.as_ref().map(Deref::deref)`
+    let _ = opt.as_ref().map(|s| &**s);
+    //~^ ERROR called `as_ref().map(..)` on an `Option` with a closure which only derefs its argument
+    //~| NOTE consider using `opt.as_ref().map(Deref::deref)`
+    let _ = v.as_ref().map(|v| v.as_slice());
+    //~^ ERROR called `as_ref().map(..)` on an `Option` with a closure which only derefs its argument
+    //~| NOTE consider using `v.as_ref().map(Deref::deref)`
+    let _ = opt.as_ref().map(String::as_str);
+    //~^ ERROR called `as_ref().map(String::as_str)` on an `Option`, which only derefs its argument
+    //~| NOTE consider using `opt.as_ref().map(Deref::deref)`
+    let _ = v.as_ref().map(Vec::as_slice);
+    //~^ ERROR called `as_ref().map(Vec::as_slice)` on an `Option`, which only derefs its argument
+    //~| NOTE consider using `v.as_ref().map(Deref::deref)`
+
+    // ok
+    let _ = opt.as_ref().map(String::len);
+    let _ = opt.as_ref().map(|s| s.len());
+    let name = Some(Name(String::from("foo")));
+    let _ = name.as_ref().map(|n| n.as_str());
+    let _ = name.as_ref().map(Name::as_str);
+    let _ = opt.as_ref().map(|s| &s[1..]);
+}
+
+fn noop_arithmetic_method() {
+    let x = 42u32;
+
+    let _ = x.checked_add(0);
+    //~^ ERROR calling `.checked_add(0)` does nothing
+    //~| HELP try
+    //~| SUGGESTION let _ = Some(x);
+    let _ = x.checked_mul(1);
+    //~^ ERROR calling `.checked_mul(1)` does nothing
+    //~| HELP try
+    //~| SUGGESTION let _ = Some(x);
+    let _ = x.saturating_add(0);
+    //~^ ERROR calling `.saturating_add(0)` does nothing
+    //~| HELP try
+    //~| SUGGESTION let _ = x;
+    let _ = x.saturating_sub(0);
+    //~^ ERROR calling `.saturating_sub(0)` does nothing
+    //~| HELP try
+    //~| SUGGESTION let _ = x;
+    let _ = x.saturating_mul(1);
+    //~^ ERROR calling `.saturating_mul(1)` does nothing
+    //~| HELP try
+    //~| SUGGESTION let _ = x;
+    let _ = x.wrapping_add(0);
+    //~^ ERROR calling `.wrapping_add(0)` does nothing
+    //~| HELP try
+    //~| SUGGESTION let _ = x;
+    let _ = x.wrapping_mul(1);
+    //~^ ERROR calling `.wrapping_mul(1)` does nothing
+    //~| HELP try
+    //~| SUGGESTION let _ = x;
+
+    // ok
+    let _ = x.wrapping_add(1);
+    let _ = x.saturating_mul(0);
+    let _ = 42i32.wrapping_mul(-1);
+}
+
+fn iter_cloned_collect() {
+    let v = vec![1, 2, 3];
+    let s: &[u32] = &[1, 2, 3];
+
+    let _: Vec<u32> = s.iter().cloned().collect();
+    //~^ ERROR called `iter().cloned().collect()` on a slice to create a `Vec`
+    //~| HELP try this
+    //~| SUGGESTION let _: Vec<u32> = s.to_vec();
+    let _ = v.iter().cloned().collect::<Vec<_>>();
+    //~^ ERROR called `iter().cloned().collect()` on a `Vec` to create a `Vec`
+    //~| HELP try this
+    //~| SUGGESTION let _ = v.clone();
+    let r = &v;
+    let _: Vec<u32> = r.iter().cloned().collect();
+    //~^ ERROR called `iter().cloned().collect()` on a `Vec` to create a `Vec`
+    //~| HELP try this
+    //~| SUGGESTION let _: Vec<u32> = r.clone();
+    let _: Vec<_> = [1, 2, 3].iter().cloned().collect();
+    //~^ ERROR called `iter().cloned().collect()` on a slice to create a `Vec`
+    //~| HELP try this
+    //~| SUGGESTION let _: Vec<_> = [1, 2, 3].to_vec();
+
+    // ok
+    let _: std::collections::HashSet<u32> = s.iter().cloned().collect();
+    let _: Vec<u32> = s.iter().rev().cloned().collect();
+}
+
+fn double_owning_conversion() {
+    let s: &[u32] = &[1, 2, 3];
+
+    let _ = s.to_owned().to_vec();
+    //~^ ERROR this creates a `Vec` and then clones it
+    //~| HELP use `to_vec()` once
+    //~| SUGGESTION let _ = s.to_vec();
+    let _ = s.to_vec().to_owned();
+    //~^ ERROR this creates a `Vec` and then clones it
+    //~| HELP use `to_vec()` once
+    //~| SUGGESTION let _ = s.to_vec();
+
+    // ok
+    let _ = s.to_vec();
+    let _ = s.to_owned();
+}
+
+fn chars_count_empty() {
+    let s = String::from("foo");
+
+    let _ = s.chars().count() == 0;
+    //~^ ERROR counting the `char`s of a string to check whether it is empty
+    //~| HELP use `.is_empty()` instead
+    //~| SUGGESTION let _ = s.is_empty();
+    let _ = "foo".chars().count() > 0;
+    //~^ ERROR counting the `char`s of a string to check whether it is empty
+    //~| HELP use `.is_empty()` instead
+    //~| SUGGESTION let _ = !"foo".is_empty();
+    let _ = 0 != s.chars().count();
+    //~^ ERROR counting the `char`s of a string to check whether it is empty
+    //~| HELP use `.is_empty()` instead
+    //~| SUGGESTION let _ = !s.is_empty();
+
+    // ok, the number of `char`s is not the length
+    let _ = s.chars().count() == 3;
+    let _ = s.chars().count() > 1;
+}
+
+fn bytes_count_to_len() {
+    let s = String::from("foo");
+
+    let _ = s.bytes().count();
+    //~^ ERROR using `bytes().count()` to get the length of a string
+    //~| HELP use `len()` instead
+    //~| SUGGESTION let _ = s.len();
+    let _ = "foo".bytes().count();
+    //~^ ERROR using `bytes().count()` to get the length of a string
+    //~| HELP use `len()` instead
+    //~| SUGGESTION let _ = "foo".len();
+
+    // ok, not every byte is counted
+    let _ = s.bytes().filter(|&b| b < 128).count();
+    let _ = s.bytes().skip(1).count();
+}
+
+fn drain_collect(r: &mut Vec<u32>) {
+    let mut v = vec![1, 2, 3];
+
+    let _: Vec<_> = v.drain(..).collect();
+    //~^ ERROR you are moving all the elements of a `Vec` into a new one
+    //~| HELP use `std::mem::replace` instead
+    //~| SUGGESTION let _: Vec<_> = std::mem::replace(&mut v, Vec::new());
+    //~| NOTE if the `Vec` is not used anymore, it can simply be moved
+    let _ = r.drain(..).collect::<Vec<_>>();
+    //~^ ERROR you are moving all the elements of a `Vec` into a new one
+    //~| HELP use `std::mem::replace` instead
+    //~| SUGGESTION let _ = std::mem::replace(r, Vec::new());
+    //~| NOTE if the `Vec` is not used anymore, it can simply be moved
+
+    // ok
+    let _: Vec<_> = v.drain(1..).collect();
+    let _: std::collections::HashSet<_> = v.drain(..).collect();
+}
+
+fn iter_nth_unwrap() {
+    let v = vec![1, 2, 3];
+    let s: &[u32] = &[1, 2, 3];
+
+    let _ = v.iter().nth(2).unwrap();
+    //~^ ERROR called `.iter().nth(..).unwrap()` on a slice-like container
+    //~| HELP try this
+    //~| SUGGESTION let _ = &v[2];
+    //~| ERROR used unwrap() on an Option
+    let _ = s.iter().nth(1).unwrap();
+    //~^ ERROR called `.iter().nth(..).unwrap()` on a slice-like container
+    //~| HELP try this
+    //~| SUGGESTION let _ = &s[1];
+    //~| ERROR used unwrap() on an Option
+
+    // ok
+    let _ = v.iter().nth(2);
+    let _ = v.iter().skip(1).nth(2).unwrap(); //~ERROR used unwrap() on an Option
+}
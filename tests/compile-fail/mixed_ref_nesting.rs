@@ -0,0 +1,30 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![deny(mixed_ref_nesting)]
+#![allow(dead_code, unused_variables)]
+
+fn mut_shared(x: &mut &u32) {} //~ERROR reference to a reference of a different mutability
+//~| NOTE this only allows to change which value the inner reference points to
+
+fn shared_mut(x: & &mut u32) {} //~ERROR reference to a reference of a different mutability
+//~| NOTE the value behind the inner `&mut` cannot be mutated
+
+fn ret<'a, 'b>(x: &'a mut &'b mut u32) -> &'a &'b mut u32 { //~ERROR reference to a reference of a different mutability
+//~| NOTE the value behind the inner `&mut` cannot be mutated
+    x
+}
+
+fn nested(x: Option<&mut &[u8]>) {} //~ERROR reference to a reference of a different mutability
+//~| NOTE this only allows to change which value the inner reference points to
+
+trait Foo {
+    fn foo(&self, x: &mut &str); //~ERROR reference to a reference of a different mutability
+    //~| NOTE this only allows to change which value the inner reference points to
+}
+
+// same mutability is fine
+fn shared_shared(x: &&u32) {}
+fn mut_mut(x: &mut &mut u32) {}
+
+fn main() {
+}
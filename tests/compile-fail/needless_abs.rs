@@ -0,0 +1,24 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![deny(needless_abs)]
+#![allow(unused)]
+
+fn main() {
+    let x: u32 = 42;
+    let y: i32 = -42;
+    let f = -4.2f64;
+
+    let _ = (x as f64).abs(); //~ERROR this call to `.abs()` does nothing
+    //~| NOTE the value is cast from an unsigned integer
+    let _ = (x as f32).abs(); //~ERROR this call to `.abs()` does nothing
+    //~| NOTE the value is cast from an unsigned integer
+    let _ = f.abs().abs(); //~ERROR this call to `.abs()` does nothing
+    //~| NOTE the value is already the result of `.abs()`
+    let _ = y.abs().abs(); //~ERROR this call to `.abs()` does nothing
+    //~| NOTE the value is already the result of `.abs()`
+
+    // ok
+    let _ = (y as f64).abs();
+    let _ = f.abs();
+    let _ = y.abs();
+}
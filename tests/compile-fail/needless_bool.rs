@@ -11,3 +11,66 @@ fn main() {
     if x { false } else { true }; //~ERROR you can reduce this if-then-else expression to just `!x`
     if x { x } else { false }; // would also be questionable, but we don't catch this yet
 }
+
+#[allow(needless_return)]
+#[deny(needless_bool)]
+fn if_return(x: bool) -> bool {
+    if x { return true; } else { return false; } //~ERROR you can reduce this if-then-else expression to just `return x`
+}
+
+#[allow(needless_return)]
+#[deny(needless_bool)]
+fn if_return_negated(x: bool) -> bool {
+    if x { return false } else { return true } //~ERROR you can reduce this if-then-else expression to just `return !x`
+}
+
+#[allow(if_same_then_else, needless_return)]
+#[deny(needless_bool)]
+fn if_return_always(x: bool) -> bool {
+    if x { return true; } else { return true; } //~ERROR this if-then-else expression will always return true
+}
+
+#[allow(needless_return)]
+#[deny(needless_bool)]
+fn if_return_ok(x: bool) -> bool {
+    if x { return x; } else { return false; }
+}
+
+#[allow(needless_return)]
+#[deny(needless_bool)]
+fn if_return_without_else(x: bool) -> bool {
+    if x { //~ERROR you can reduce this `if` and the following `return` to just `return x`
+        return true;
+    }
+    return false;
+}
+
+#[allow(needless_return)]
+#[deny(needless_bool)]
+fn if_return_without_else_negated(x: bool) -> bool {
+    if x { //~ERROR you can reduce this `if` and the following `return` to just `return !x`
+        return false;
+    }
+    return true;
+}
+
+#[allow(needless_return)]
+#[deny(needless_bool)]
+fn if_return_without_else_semi(x: bool) -> bool {
+    if x { //~ERROR you can reduce this `if` and the following `return` to just `return x`
+        return true;
+    };
+    return false;
+}
+
+#[allow(needless_return)]
+#[deny(needless_bool)]
+fn if_return_without_else_ok(x: bool, y: bool) -> bool {
+    if x {
+        return true;
+    }
+    if y {
+        return false;
+    }
+    x
+}
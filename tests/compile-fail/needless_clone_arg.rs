@@ -0,0 +1,42 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![deny(needless_clone_arg)]
+
+fn borrow(_: &String) {}
+fn borrow_str(_: &str) {}
+fn take(_: String) {}
+fn mutate(s: &mut String) { s.push('!'); }
+
+struct Foo;
+
+impl Foo {
+    fn borrow(&self, _: &Vec<u8>) {}
+}
+
+fn main() {
+    let s = String::from("foo");
+    let r = &s;
+    let v = vec![1, 2, 3];
+
+    borrow(&s.clone()); //~ERROR this argument is a reference to a clone
+                        //~^ HELP borrow the original value instead
+                        //~| SUGGESTION borrow(&s);
+
+    borrow_str(&s.clone()); //~ERROR this argument is a reference to a clone
+                            //~^ HELP borrow the original value instead
+                            //~| SUGGESTION borrow_str(&s);
+
+    borrow(&r.clone()); //~ERROR this argument is a reference to a clone
+                        //~^ HELP borrow the original value instead
+                        //~| SUGGESTION borrow(r);
+
+    Foo.borrow(&v.clone()); //~ERROR this argument is a reference to a clone
+                            //~^ HELP borrow the original value instead
+                            //~| SUGGESTION Foo.borrow(&v);
+
+    // ok, the clone is moved into the callee
+    take(s.clone());
+
+    // ok, the clone is mutated
+    mutate(&mut s.clone());
+}
@@ -0,0 +1,64 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![deny(needless_continue)]
+#![allow(unused)]
+
+fn main() {
+    let v = vec![1, 2, 3];
+
+    for x in &v {
+        println!("{}", x);
+        continue; //~ERROR this `continue` is needless, it is the last statement of the loop body
+        //~^ HELP remove it
+    }
+
+    let mut i = 0;
+    while i < 10 {
+        i += 1;
+        if i % 2 == 0 {
+            println!("even");
+            continue; //~ERROR this `continue` is needless, the loop body ends after this `if`
+            //~^ HELP remove it
+        } else {
+            println!("odd");
+            continue //~ERROR this `continue` is needless, the loop body ends after this `if`
+            //~^ HELP remove it
+        }
+    }
+
+    for x in &v {
+        if *x == 2 {
+            continue; //~ERROR this `continue` is needless, the loop body ends after this `if`
+            //~^ HELP consider inverting the condition, putting the `else` block in the `if` block
+        } else {
+            println!("{}", x);
+        }
+    }
+
+    let mut it = v.iter();
+    while let Some(x) = it.next() {
+        println!("{}", x);
+        if *x == 2 {
+            continue; //~ERROR this `continue` is needless, the loop body ends after this `if`
+            //~^ HELP remove the `if`, it does nothing but evaluate its condition
+        }
+    }
+
+    // ok, not the last statement
+    for x in &v {
+        if *x == 2 {
+            continue;
+        }
+        println!("{}", x);
+    }
+
+    // ok, labeled `continue` of an outer loop
+    'outer: for x in &v {
+        for y in &v {
+            println!("{}", y);
+            if x == y {
+                continue 'outer;
+            }
+        }
+    }
+}
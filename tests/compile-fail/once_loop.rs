@@ -0,0 +1,60 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![deny(once_loop)]
+#![allow(unused, needless_continue)]
+
+fn main() {
+    let v = vec![1, 2, 3];
+
+    loop { //~ERROR this loop never loops, its body is executed exactly once
+    //~^ HELP remove the `loop` and the final `break`
+        println!("once");
+        break;
+    }
+
+    'a: loop { //~ERROR this loop never loops, its body is executed exactly once
+    //~^ HELP remove the `loop` and the final `break`
+        for x in &v {
+            if *x == 2 {
+                break;
+            }
+        }
+        break 'a
+    }
+
+    // ok, conditional `break`
+    let mut i = 0;
+    loop {
+        i += 1;
+        if i == 10 {
+            break;
+        }
+    }
+
+    // ok, there is a `continue`
+    loop {
+        i += 1;
+        if i < 20 {
+            continue;
+        }
+        break;
+    }
+
+    // ok, an inner loop continues this one
+    'b: loop {
+        for x in &v {
+            if *x == i {
+                continue 'b;
+            }
+        }
+        break;
+    }
+
+    // ok, the final `break` is for an outer loop
+    'c: loop {
+        loop {
+            i += 1;
+            break 'c;
+        }
+    }
+}
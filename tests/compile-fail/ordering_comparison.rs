@@ -0,0 +1,49 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![deny(ordering_comparison)]
+#![allow(unused)]
+
+use std::cmp::Ordering;
+
+fn main() {
+    let a = 1u32;
+    let b = 2u32;
+    let x = 1.0f64;
+    let y = 2.0f64;
+
+    let _ = a.cmp(&b) == Ordering::Less;
+    //~^ ERROR comparing the result of `.cmp()` to an `Ordering`
+    //~| HELP use a comparison operator instead
+    //~| SUGGESTION let _ = a < b;
+    let _ = a.cmp(&b) == Ordering::Greater;
+    //~^ ERROR comparing the result of `.cmp()` to an `Ordering`
+    //~| HELP use a comparison operator instead
+    //~| SUGGESTION let _ = a > b;
+    let _ = Ordering::Equal == a.cmp(&b);
+    //~^ ERROR comparing the result of `.cmp()` to an `Ordering`
+    //~| HELP use a comparison operator instead
+    //~| SUGGESTION let _ = a == b;
+    let _ = a.cmp(&b) != Ordering::Less;
+    //~^ ERROR comparing the result of `.cmp()` to an `Ordering`
+    //~| HELP use a comparison operator instead
+    //~| SUGGESTION let _ = a >= b;
+
+    let _ = x.partial_cmp(&y) == Some(Ordering::Less);
+    //~^ ERROR comparing the result of `.partial_cmp()` to an `Ordering`
+    //~| HELP use a comparison operator instead
+    //~| SUGGESTION let _ = x < y;
+    let _ = x.partial_cmp(&y) == Some(Ordering::Greater);
+    //~^ ERROR comparing the result of `.partial_cmp()` to an `Ordering`
+    //~| HELP use a comparison operator instead
+    //~| SUGGESTION let _ = x > y;
+    let _ = x.partial_cmp(&y) == Some(Ordering::Equal);
+    //~^ ERROR comparing the result of `.partial_cmp()` to an `Ordering`
+    //~| HELP use a comparison operator instead
+    //~| SUGGESTION let _ = x == y;
+
+    // ok, `x >= y` is not the same with NaNs
+    let _ = x.partial_cmp(&y) != Some(Ordering::Less);
+    // ok, the `Option` is compared to an `Ordering`
+    let _ = x.partial_cmp(&y).unwrap() == Ordering::Less;
+    let _ = x.partial_cmp(&y) == None;
+}
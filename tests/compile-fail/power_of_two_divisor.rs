@@ -0,0 +1,24 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![deny(power_of_two_divisor)]
+#![allow(unused)]
+
+const EIGHT: usize = 8;
+
+fn main() {
+    let x = 42usize;
+
+    let _ = x / 8; //~ERROR this division by a power of two could be a shift
+    //~^ NOTE this is the same as shifting right by 3
+    let _ = x % 16; //~ERROR this remainder by a power of two could be a mask
+    //~^ NOTE this is the same as masking with `15`
+    let _ = x / EIGHT; //~ERROR this division by a power of two could be a shift
+    //~^ NOTE this is the same as shifting right by 3
+
+    // ok
+    let _ = x / 3;
+    let _ = x % 1;
+    let _ = x / 1;
+    let _ = 42i32 / 8;
+    let _ = 42.0 / 8.0;
+}
@@ -1,7 +1,7 @@
 #![feature(plugin)]
 #![plugin(clippy)]
 #![allow(unused)]
-#![deny(ptr_arg)]
+#![deny(ptr_arg, ptr_ret, byte_vec_arg)]
 
 fn do_vec(x: &Vec<i64>) { //~ERROR writing `&Vec<_>` instead of `&[_]`
     //Nothing here
@@ -19,6 +19,50 @@ fn do_str_mut(x: &mut String) { // no error here
     //Nothing here either
 }
 
+fn do_box(x: &Box<i32>) -> i32 {
+    //~^ ERROR writing `&Box<_>` instead of `&_`
+    //~| HELP change this to
+    //~| SUGGESTION fn do_box(x: &i32) -> i32 {
+    **x + 1
+}
+
+fn do_box_method(x: &Box<String>) -> usize { //~ERROR writing `&Box<_>` instead of `&_`
+    x.len()
+}
+
+fn do_box_clone(x: &Box<i32>) -> Box<i32> { // no error, the box is cloned
+    x.clone()
+}
+
+fn do_box_mut(x: &mut Box<i32>) { // no error here
+    **x = 0;
+}
+
+fn checksum(data: Vec<u8>) -> u8 { //~ERROR this argument is a `Vec<u8>` which is only read
+    data.iter().fold(0, |acc, b| acc ^ b)
+}
+
+fn first_byte(data: Vec<u8>) -> Option<u8> { //~ERROR this argument is a `Vec<u8>` which is only read
+    if data.is_empty() { None } else { Some(data[0]) }
+}
+
+fn consume(data: Vec<u8>) -> Vec<u8> { // no error, moved out
+    data
+}
+
+fn grow(mut data: Vec<u8>) -> usize { // no error, mutable binding
+    data.push(0);
+    data.len()
+}
+
+fn into_iter(data: Vec<u8>) -> u8 { // no error, consumed by `into_iter`
+    data.into_iter().fold(0, |acc, b| acc ^ b)
+}
+
+fn not_bytes(data: Vec<u32>) -> usize { // no error, not `Vec<u8>`
+    data.len()
+}
+
 fn main() {
 }
 
@@ -36,3 +80,28 @@ impl Foo for Bar {
     fn do_vec(x: &Vec<i64>) {}
     fn do_item(x: &Vec<u8>) {}  
 }
+
+struct Holder {
+    bytes: Vec<u8>,
+    name: String,
+}
+
+impl Holder {
+    fn bytes(&self) -> &Vec<u8> {
+        //~^ ERROR returning `&Vec<_>` instead of `&[_]`
+        //~| HELP change this to
+        //~| SUGGESTION fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    fn name<'a>(&'a self) -> &'a String {
+        //~^ ERROR returning `&String` instead of `&str`
+        //~| HELP change this to
+        //~| SUGGESTION fn name<'a>(&'a self) -> &'a str {
+        &self.name
+    }
+
+    fn bytes_mut(&mut self) -> &mut Vec<u8> { // no error here
+        &mut self.bytes
+    }
+}
@@ -0,0 +1,103 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![deny(redundant_else)]
+#![allow(unused)]
+
+fn ret(x: u32) -> u32 {
+    if x == 0 {
+        return 1;
+    } else { //~ERROR redundant `else` block
+    //~^ HELP remove the `else` and move its content after the `if` block
+        println!("{}", x);
+    }
+    x
+}
+
+// the final `if` of a function body is left to `NEEDLESS_RETURN`
+#[allow(needless_return)]
+fn ret_tail(x: u32) -> u32 {
+    if x == 0 {
+        return 1;
+    } else {
+        x * 2
+    }
+}
+
+fn brk() {
+    for i in 0..10 {
+        if i == 5 {
+            break;
+        } else { //~ERROR redundant `else` block
+        //~^ HELP remove the `else` and move its content after the `if` block
+            println!("{}", i);
+        }
+    }
+}
+
+fn cont() {
+    for i in 0..10 {
+        if i % 2 == 0 {
+            println!("even");
+            continue
+        } else { //~ERROR redundant `else` block
+        //~^ HELP remove the `else` and move its content after the `if` block
+            println!("{}", i);
+        }
+        println!("done");
+    }
+}
+
+// the `continue` at the end of the loop body is left to `NEEDLESS_CONTINUE`
+#[allow(needless_continue)]
+fn cont_tail() {
+    for i in 0..10 {
+        if i % 2 == 0 {
+            continue;
+        } else {
+            println!("{}", i);
+        }
+    }
+}
+
+fn pan(x: Option<u32>) -> u32 {
+    if x.is_none() {
+        panic!("no value");
+    } else { //~ERROR redundant `else` block
+    //~^ HELP remove the `else` and move its content after the `if` block
+        println!("{:?}", x);
+    }
+    x.unwrap()
+}
+
+fn falls_through(x: u32) -> u32 {
+    let mut y = x;
+    if x == 0 {
+        y = 1;
+    } else {
+        y = 2;
+    }
+    y
+}
+
+fn not_last(x: u32) -> u32 {
+    if x == 0 {
+        if x == 1 {
+            return 2;
+        }
+    } else {
+        return 3;
+    }
+    x
+}
+
+fn else_if(x: u32) -> u32 {
+    if x == 0 {
+        return 1;
+    } else if x == 1 {
+        return 2;
+    }
+    x
+}
+
+fn main() {
+}
@@ -0,0 +1,34 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![deny(redundant_trait_bound)]
+#![allow(unused)]
+
+use std::fmt::Debug;
+
+fn dup<T: Clone + Clone>(x: T) {}
+//~^ ERROR redundant trait bounds
+//~| HELP remove the redundant bounds
+//~| SUGGESTION fn dup<T: Clone>(x: T) {}
+
+fn copy_clone<T: Copy + Clone>(x: T) {}
+//~^ ERROR redundant trait bounds
+//~| HELP remove the redundant bounds
+//~| SUGGESTION fn copy_clone<T: Copy>(x: T) {}
+
+fn where_clause<T>(x: T) where T: Debug + Clone + Debug {}
+//~^ ERROR redundant trait bounds
+//~| HELP remove the redundant bounds
+//~| SUGGESTION fn where_clause<T>(x: T) where T: Debug + Clone {}
+
+struct S<T: Clone + Copy + Debug>(T);
+//~^ ERROR redundant trait bounds
+//~| HELP remove the redundant bounds
+//~| SUGGESTION struct S<T: Copy + Debug>(T);
+
+// ok
+fn different<T: Clone + Debug>(x: T) {}
+fn generic<T: Into<u32> + Into<u64>>(x: T) {}
+fn single<T: Copy>(x: T) {}
+
+fn main() {
+}
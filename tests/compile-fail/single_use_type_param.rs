@@ -0,0 +1,43 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![deny(single_use_type_param)]
+#![allow(unused)]
+
+fn consume<T>(x: T) { //~ERROR type parameter `T` has no bounds and is used only once
+//~^ NOTE consider using a concrete type instead
+}
+
+fn len<T>(v: &[u32], _: Option<T>) -> usize { //~ERROR type parameter `T` has no bounds and is used only once
+//~^ NOTE consider using a concrete type instead
+    v.len()
+}
+
+// ok, bounded
+fn show<T: std::fmt::Debug>(x: T) {
+    println!("{:?}", x);
+}
+
+fn show_where<T>(x: T) where T: std::fmt::Debug {
+    println!("{:?}", x);
+}
+
+// ok, used several times
+fn id<T>(x: T) -> T {
+    x
+}
+
+fn size<T>(_: &T) -> usize {
+    std::mem::size_of::<T>()
+}
+
+// ok, only in the return type
+fn make<T>() -> T {
+    unimplemented!()
+}
+
+fn wrap<T>(x: T) -> Option<T> {
+    Some(x)
+}
+
+fn main() {
+}
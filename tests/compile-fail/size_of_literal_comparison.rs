@@ -0,0 +1,29 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![deny(size_of_literal_comparison)]
+#![allow(unused)]
+
+use std::mem;
+
+struct Foo {
+    a: u64,
+    b: u32,
+}
+
+fn main() {
+    assert_eq!(mem::size_of::<Foo>(), 16);
+    //~^ ERROR comparing the size of a type to a hardcoded number
+    //~| NOTE the size depends on the platform and on the layout chosen by the compiler
+    assert!(mem::size_of::<usize>() == 8);
+    //~^ ERROR comparing the size of a type to a hardcoded number
+    //~| NOTE the size depends on the platform and on the layout chosen by the compiler
+    if 4 < mem::size_of_val(&0usize) {
+    //~^ ERROR comparing the size of a type to a hardcoded number
+    //~| NOTE the size depends on the platform and on the layout chosen by the compiler
+    }
+
+    // ok
+    assert_eq!(mem::size_of::<Foo>(), mem::size_of::<(u64, u32)>());
+    assert_eq!(Vec::<u8>::new().len(), 0);
+    let _ = mem::size_of::<Foo>() * 8;
+}
@@ -0,0 +1,33 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![deny(static_mut_ref_return)]
+#![allow(dead_code)]
+
+static mut COUNTER: u32 = 0;
+
+fn counter() -> &'static mut u32 { //~ERROR this function returns a `&'static mut` reference
+//~| NOTE if the function can be called more than once, the returned references may alias
+    unsafe { &mut COUNTER }
+}
+
+trait Global {
+    fn global() -> &'static mut Self; //~ERROR this function returns a `&'static mut` reference
+    //~| NOTE if the function can be called more than once, the returned references may alias
+}
+
+// each call leaks a new value
+#[allow(static_mut_ref_return)]
+fn leak(x: u32) -> &'static mut u32 {
+    unsafe { &mut *Box::into_raw(Box::new(x)) }
+}
+
+fn shared() -> &'static u32 {
+    &42
+}
+
+fn reborrow<'a>(x: &'a mut u32) -> &'a mut u32 {
+    x
+}
+
+fn main() {
+}
@@ -16,6 +16,19 @@ fn add_only() { // ignores assignment distinction
     assert_eq!(&x, &z);
 }
 
+#[deny(string_add)]
+fn add_chain() {
+    let a = "a".to_owned();
+    let b = "b".to_owned();
+    let c = "c";
+
+    let _ = a.clone() + &b + c; //~ERROR you added something to a string.
+    //~^ NOTE consider using `format!("{}{}{}", a.clone(), b, c)` to build the string at once
+    let _ = a.clone() + &b + c + "d"; //~ERROR you added something to a string.
+    //~^ NOTE consider using `format!("{}{}{}{}", a.clone(), b, c, "d")` to build the string at once
+    let _ = a + &b; //~ERROR you added something to a string.
+}
+
 #[deny(string_add_assign)]
 fn add_assign_only() {
     let mut x = "".to_owned();
@@ -52,6 +65,88 @@ fn str_lit_as_bytes() {
     let ubs = "☃".as_bytes();
 }
 
+#[allow(dead_code)]
+#[deny(repeated_char_push)]
+fn repeated_char_push() {
+    let mut s = String::new();
+    let c = '-';
+    let n = 4;
+
+    for _ in 0..n { //~ERROR pushing the same `char` onto a `String` in a loop
+                    //~^ HELP consider using `s.extend(std::iter::repeat(c).take(n))`
+        s.push(c);
+    }
+
+    for _ in 0..10 { s.push(' ') } //~ERROR pushing the same `char` onto a `String` in a loop
+                                   //~^ HELP consider using `s.extend(std::iter::repeat(' ').take(10))`
+
+    // the pushed char changes
+    for i in 0..10u8 {
+        s.push((b'a' + i) as char);
+    }
+    let chars = ['a', 'b'];
+    for c in chars.iter().cloned() {
+        s.push(c);
+    }
+
+    // not a `char`
+    for _ in 0..n {
+        s.push_str("ab");
+    }
+    let mut v = Vec::new();
+    for _ in 0..n {
+        v.push(c);
+    }
+}
+
+#[allow(dead_code)]
+#[deny(split_collect_index)]
+fn split_collect_index() {
+    let path = "a/b/c";
+    let owned = path.to_owned();
+
+    let _ = path.split('/').collect::<Vec<_>>()[0]; //~ERROR collecting the parts of a `split` into a `Vec`
+    //~^ HELP try
+    //~| SUGGESTION path.split('/').next().unwrap()
+    let _ = owned.split("/").collect::<Vec<_>>()[2]; //~ERROR collecting the parts of a `split` into a `Vec`
+    //~^ HELP try
+    //~| SUGGESTION owned.split("/").nth(2).unwrap()
+
+    // the `Vec` is used for more than one index
+    let parts = path.split('/').collect::<Vec<_>>();
+    let _ = parts[0];
+    let _ = parts[1];
+
+    // not a constant index
+    let i = 1;
+    let _ = path.split('/').collect::<Vec<_>>()[i];
+}
+
+#[allow(dead_code)]
+#[deny(manual_string_fold)]
+fn manual_string_fold() {
+    let words = vec!["a", "b", "c"];
+
+    let _ = words.iter().cloned().fold(String::new(), |mut acc, w| { acc.push_str(w); acc });
+    //~^ ERROR this `fold` only pushes every element onto a `String`
+    //~| HELP consider using `words.iter().cloned().collect::<String>()`
+    let _ = "abc".chars().fold(String::new(), |mut acc, c| {
+    //~^ ERROR this `fold` only pushes every element onto a `String`
+    //~| HELP consider using `"abc".chars().collect::<String>()`
+        acc.push(c);
+        acc
+    });
+
+    // `String` cannot be collected from `&&str`
+    let words = vec!["a", "b", "c"];
+    let _ = words.iter().fold(String::new(), |mut acc, w| { acc.push_str(w); acc });
+
+    // the fold does more than concatenating
+    let _ = words.iter().fold(String::new(), |mut acc, w| { acc.push_str(w); acc.push(' '); acc });
+    let _ = words.iter().fold(0, |acc, w| acc + w.len());
+    let _ = words.iter().fold(String::from("> "), |mut acc, w| { acc.push_str(w); acc });
+}
+
 fn main() {
     add_only();
     add_assign_only();
@@ -1,6 +1,6 @@
 #![feature(plugin)]
 #![plugin(clippy)]
-#![deny(useless_transmute)]
+#![deny(useless_transmute, transmute_int_to_bool)]
 
 extern crate core;
 
@@ -41,5 +41,15 @@ fn main() {
         let _: Vec<u32> = std::intrinsics::transmute(my_vec());
         let _: Vec<u32> = std::mem::transmute(my_vec());
         let _: Vec<u32> = my_transmute(my_vec());
+
+        let byte = 1_u8;
+        let _: bool = std::mem::transmute(byte);
+        //~^ ERROR transmute from a `u8` to a `bool`
+        //~| HELP consider comparing to zero instead
+        //~| SUGGESTION let _: bool = byte != 0;
+
+        // only `u8` has the same size as `bool`
+        let _: u8 = std::mem::transmute(true);
+        let _: i8 = std::mem::transmute(byte);
     }
 }
@@ -0,0 +1,44 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![deny(while_const_true)]
+#![allow(unused, while_true)]
+
+const RUNNING: bool = true;
+
+fn main() {
+    let mut i = 0;
+
+    while true { //~ERROR this `while` condition is always `true`
+    //~^ HELP use `loop` instead
+    //~| SUGGESTION loop {
+        i += 1;
+        if i > 10 {
+            break;
+        }
+    }
+
+    'outer: while !false { //~ERROR this `while` condition is always `true`
+    //~^ HELP use `loop` instead
+    //~| SUGGESTION 'outer: loop {
+        break 'outer;
+    }
+}
+
+fn never_returns() -> ! {
+    while true { //~ERROR this `while` condition is always `true`
+    //~^ HELP use `loop` instead
+    //~| SUGGESTION loop {
+    }
+    panic!()
+}
+
+fn ok(x: bool) {
+    while x {
+    }
+    while false {
+    }
+    // a named constant is probably a configuration flag
+    while RUNNING {
+        break;
+    }
+}
@@ -124,6 +124,32 @@ fn main() {
     }
 }
 
+fn while_let_on_iterator_suggestions() {
+    let v = vec![1, 2, 3];
+
+    let mut it = v.iter();
+    while let Some(x) = it.next() {
+    //~^ ERROR this loop could be written as a `for` loop
+    //~| HELP try
+    //~| SUGGESTION for x in v.iter() {
+        println!("{}", x);
+    }
+
+    let mut it = v.iter();
+    println!("{}", v.len());
+    while let Some(x) = it.next() { //~ERROR this loop could be written as a `for` loop
+                                    //~^ HELP try
+                                    //~| SUGGESTION for x in it {
+        println!("{}", x);
+    }
+
+    // `it` is advanced in the loop body, this can't be a `for` loop
+    let mut it = v.iter();
+    while let Some(x) = it.next() {
+        println!("{} {:?}", x, it.next());
+    }
+}
+
 // regression test (#360)
 // this should not panic
 // it's okay if further iterations of the lint
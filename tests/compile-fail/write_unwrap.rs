@@ -0,0 +1,56 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![deny(write_unwrap, infallible_write_unwrap)]
+#![allow(dead_code)]
+
+use std::fmt;
+use std::fmt::Write as FmtWrite;
+use std::io::{self, Write};
+
+struct Foo(u32);
+
+impl fmt::Display for Foo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0).unwrap();
+        //~^ ERROR called `unwrap()` on the result of `write!` in a function returning a `Result`
+        //~| HELP consider passing the error on with `try!`
+        //~| SUGGESTION try!(write!(f, "{}", self.0));
+        writeln!(f, "!").unwrap();
+        //~^ ERROR called `unwrap()` on the result of `write!` in a function returning a `Result`
+        //~| HELP consider passing the error on with `try!`
+        //~| SUGGESTION try!(writeln!(f, "!"));
+        Ok(())
+    }
+}
+
+fn to_stdout(x: u32) -> io::Result<()> {
+    let mut out = io::stdout();
+    writeln!(out, "{}", x).unwrap();
+    //~^ ERROR called `unwrap()` on the result of `write!` in a function returning a `Result`
+    //~| HELP consider passing the error on with `try!`
+    //~| SUGGESTION try!(writeln!(out, "{}", x));
+    Ok(())
+}
+
+// the function cannot return the error
+fn no_result(x: u32) {
+    writeln!(io::stdout(), "{}", x).unwrap();
+}
+
+// `try!` would return from the closure
+fn in_closure(x: u32) -> io::Result<()> {
+    let print = |y: u32| writeln!(io::stdout(), "{}", y).unwrap();
+    print(x);
+    Ok(())
+}
+
+// writing to a `String` cannot fail, only `INFALLIBLE_WRITE_UNWRAP` fires
+fn to_string(x: u32) -> fmt::Result {
+    let mut s = String::new();
+    write!(s, "{}", x).unwrap();
+    //~^ ERROR called `unwrap()` on the result of writing to an in-memory buffer
+    Ok(())
+}
+
+fn main() {
+}